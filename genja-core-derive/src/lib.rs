@@ -1,6 +1,7 @@
-//! This crate provides two procedural macros: `DerefMacro` and `DerefMutMacro`.
-//! These macros allow you to implement the `Deref` and `DerefMut` traits
-//! for your custom types.
+//! This crate provides the procedural macros `DerefMacro`, `DerefMutMacro`,
+//! `IndexMacro` and `DelegateMacro`. These macros allow you to implement the
+//! `Deref`, `DerefMut`, `Index` and `IndexMut` traits for your custom types,
+//! or to expose selected methods of the wrapped collection instead.
 //!
 //! # Example
 //! ```
@@ -29,7 +30,8 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{DeriveInput, parse_macro_input};
+use syn::punctuated::Punctuated;
+use syn::{Data, DeriveInput, Fields, Ident, Token, Type, parse_macro_input};
 
 /// Generates an implementation of the `Deref` trait for the given type.
 ///
@@ -94,3 +96,238 @@ pub fn derive_deref_mut(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Returns the type of the single unnamed field of a newtype struct.
+fn newtype_field(input: &DeriveInput) -> syn::Result<&Type> {
+    if let Data::Struct(data) = &input.data
+        && let Fields::Unnamed(fields) = &data.fields
+        && fields.unnamed.len() == 1
+    {
+        return Ok(&fields.unnamed[0].ty);
+    }
+    Err(syn::Error::new_spanned(
+        &input.ident,
+        "expected a struct with a single unnamed field",
+    ))
+}
+
+/// Kind of inner collection an `IndexMacro` wrapper indexes into.
+enum IndexKind {
+    /// Maps keyed by strings, indexed with `&str`.
+    Map,
+    /// Vectors, indexed with `usize`.
+    Vec,
+}
+
+/// Reads `#[index(map)]` or `#[index(vec)]`, falling back to the last path
+/// segment of the field type: `*Map` types are maps, `Vec` is a vector.
+fn index_kind(input: &DeriveInput, field_ty: &Type) -> syn::Result<IndexKind> {
+    if let Some(attr) = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("index"))
+    {
+        let kind: Ident = attr.parse_args()?;
+        return match kind.to_string().as_str() {
+            "map" => Ok(IndexKind::Map),
+            "vec" => Ok(IndexKind::Vec),
+            _ => Err(syn::Error::new_spanned(kind, "expected `map` or `vec`")),
+        };
+    }
+    if let Type::Path(path) = field_ty
+        && let Some(segment) = path.path.segments.last()
+    {
+        let ident = segment.ident.to_string();
+        if ident == "Vec" {
+            return Ok(IndexKind::Vec);
+        }
+        if ident.ends_with("Map") {
+            return Ok(IndexKind::Map);
+        }
+    }
+    Err(syn::Error::new_spanned(
+        field_ty,
+        "cannot tell the collection kind, add `#[index(map)]` or `#[index(vec)]`",
+    ))
+}
+
+/// Generates `Index` and `IndexMut` for a newtype over a collection.
+///
+/// Map wrappers are indexed with `&str` and vector wrappers with `usize`.
+/// The kind is inferred from the field type (`Vec`, or a type ending in
+/// `Map`) or set with `#[index(map)]`/`#[index(vec)]`, e.g. when the field
+/// type is an alias. The inner collection must implement `Index` for the
+/// key type and provide `get`/`get_mut`.
+///
+/// Missing keys panic with the key and the wrapper type name, e.g.
+/// ``Hosts has no entry `r9` ``.
+///
+/// ```
+/// use genja_core_derive::IndexMacro;
+/// use std::collections::BTreeMap;
+///
+/// #[derive(IndexMacro)]
+/// struct Ports(BTreeMap<String, u16>);
+///
+/// #[derive(IndexMacro)]
+/// struct Names(Vec<String>);
+///
+/// let mut ports = Ports(BTreeMap::from([("ssh".to_string(), 22)]));
+/// ports["ssh"] = 2222;
+/// assert_eq!(ports["ssh"], 2222);
+/// assert_eq!(Names(vec!["r1".to_string()])[0], "r1");
+/// ```
+#[proc_macro_derive(IndexMacro, attributes(index))]
+pub fn derive_index(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (field_ty, kind) = match newtype_field(&input)
+        .and_then(|field_ty| Ok((field_ty, index_kind(&input, field_ty)?)))
+    {
+        Ok(field) => field,
+        Err(error) => return error.to_compile_error().into(),
+    };
+
+    let expanded = match kind {
+        IndexKind::Map => quote! {
+            impl<'key> std::ops::Index<&'key str> for #name {
+                type Output = <#field_ty as std::ops::Index<&'key str>>::Output;
+
+                fn index(&self, key: &'key str) -> &Self::Output {
+                    match self.0.get(key) {
+                        Some(value) => value,
+                        None => panic!("{} has no entry `{}`", stringify!(#name), key),
+                    }
+                }
+            }
+
+            impl<'key> std::ops::IndexMut<&'key str> for #name {
+                fn index_mut(&mut self, key: &'key str) -> &mut Self::Output {
+                    match self.0.get_mut(key) {
+                        Some(value) => value,
+                        None => panic!("{} has no entry `{}`", stringify!(#name), key),
+                    }
+                }
+            }
+        },
+        IndexKind::Vec => quote! {
+            impl std::ops::Index<usize> for #name {
+                type Output = <#field_ty as std::ops::Index<usize>>::Output;
+
+                fn index(&self, index: usize) -> &Self::Output {
+                    let len = self.0.len();
+                    match self.0.get(index) {
+                        Some(value) => value,
+                        None => panic!(
+                            "{} index {} out of range for length {}",
+                            stringify!(#name), index, len
+                        ),
+                    }
+                }
+            }
+
+            impl std::ops::IndexMut<usize> for #name {
+                fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                    let len = self.0.len();
+                    match self.0.get_mut(index) {
+                        Some(value) => value,
+                        None => panic!(
+                            "{} index {} out of range for length {}",
+                            stringify!(#name), index, len
+                        ),
+                    }
+                }
+            }
+        },
+    };
+    TokenStream::from(expanded)
+}
+
+/// Methods `DelegateMacro` can forward to the wrapped collection.
+const DELEGATED_METHODS: &[&str] = &["len", "is_empty", "iter", "contains_key"];
+
+/// Generates inherent methods on a newtype that forward to the wrapped
+/// collection, listed in `#[delegate(...)]`.
+///
+/// Unlike `DerefMacro`, only the listed methods become part of the
+/// wrapper's API. Supported methods:
+///
+/// * `len(&self) -> usize`
+/// * `is_empty(&self) -> bool`
+/// * `iter(&self)`, returning the iterator of `&Inner`'s `IntoIterator`
+/// * `contains_key(&self, key: &str) -> bool`, for collections with a
+///   `get(&str)` method
+///
+/// ```
+/// use genja_core_derive::DelegateMacro;
+/// use std::collections::BTreeMap;
+///
+/// #[derive(DelegateMacro)]
+/// #[delegate(len, is_empty, iter, contains_key)]
+/// struct Ports(BTreeMap<String, u16>);
+///
+/// let ports = Ports(BTreeMap::from([("ssh".to_string(), 22)]));
+/// assert_eq!(ports.len(), 1);
+/// assert!(ports.contains_key("ssh"));
+/// assert_eq!(ports.iter().next(), Some((&"ssh".to_string(), &22)));
+/// ```
+#[proc_macro_derive(DelegateMacro, attributes(delegate))]
+pub fn derive_delegate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match delegate_methods(&input) {
+        Ok(methods) => methods,
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn delegate_methods(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let field_ty = newtype_field(input)?;
+    let mut methods = Vec::new();
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("delegate"))
+    {
+        let names = attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+        for method in names {
+            methods.push(match method.to_string().as_str() {
+                "len" => quote! {
+                    pub fn len(&self) -> usize {
+                        self.0.len()
+                    }
+                },
+                "is_empty" => quote! {
+                    pub fn is_empty(&self) -> bool {
+                        self.0.is_empty()
+                    }
+                },
+                "iter" => quote! {
+                    pub fn iter(&self) -> <&#field_ty as IntoIterator>::IntoIter {
+                        (&self.0).into_iter()
+                    }
+                },
+                "contains_key" => quote! {
+                    pub fn contains_key(&self, key: &str) -> bool {
+                        self.0.get(key).is_some()
+                    }
+                },
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        &method,
+                        format!(
+                            "cannot delegate `{method}`, expected one of: {}",
+                            DELEGATED_METHODS.join(", ")
+                        ),
+                    ));
+                }
+            });
+        }
+    }
+    let expanded = quote! {
+        impl #name {
+            #(#methods)*
+        }
+    };
+    Ok(TokenStream::from(expanded))
+}
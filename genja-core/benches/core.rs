@@ -0,0 +1,98 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use genja_core::inventory::{BaseBuilderHost, Host, Hosts, IncrementalLoader, Inventory};
+use genja_core::{CustomTreeMap, Genja};
+
+fn hosts_json(count: usize) -> String {
+    let hosts: Vec<String> = (1..=count)
+        .map(|i| {
+            format!(
+                r#""host{i}": {{
+                    "name": "host{i}",
+                    "hostname": "10.0.{}.{}",
+                    "port": 22,
+                    "platform": "{}",
+                    "groups": ["core", "site{}", "core"],
+                    "data": {{ "site": "site{}", "role": "edge" }}
+                }}"#,
+                i / 256,
+                i % 256,
+                if i % 2 == 0 { "eos" } else { "ios" },
+                i % 10,
+                i % 10,
+            )
+        })
+        .collect();
+    format!("{{{}}}", hosts.join(","))
+}
+
+fn inventory(count: usize) -> Inventory {
+    let mut hosts = Hosts::new();
+    for i in 1..=count {
+        hosts.add_host(
+            Host::builder(&format!("host{i}"))
+                .platform(if i % 2 == 0 { "eos" } else { "ios" })
+                .group("core")
+                .build(),
+        );
+    }
+    Inventory::builder().hosts(hosts).build()
+}
+
+fn custom_tree_map(c: &mut Criterion) {
+    let mut group = c.benchmark_group("custom_tree_map");
+    group.sample_size(10);
+    for size in [10_000, 100_000, 1_000_000] {
+        group.bench_with_input(BenchmarkId::new("insert", size), &size, |b, &size| {
+            b.iter(|| {
+                let mut tree = CustomTreeMap::new();
+                for i in 0..size {
+                    tree.insert(format!("host{i}"), i);
+                }
+                tree
+            })
+        });
+
+        let mut tree = CustomTreeMap::new();
+        for i in 0..size {
+            tree.insert(format!("host{i}"), i);
+        }
+        group.bench_with_input(BenchmarkId::new("get", size), &tree, |b, tree| {
+            b.iter(|| tree.get(black_box("host5000")))
+        });
+    }
+    group.finish();
+}
+
+fn inventory_load(c: &mut Criterion) {
+    let mut group = c.benchmark_group("inventory_load");
+    group.sample_size(10);
+    for size in [1_000, 10_000] {
+        let source = hosts_json(size);
+        group.bench_with_input(BenchmarkId::new("from_str", size), &source, |b, source| {
+            b.iter(|| serde_json::from_str::<Hosts>(source).unwrap())
+        });
+        group.bench_with_input(
+            BenchmarkId::new("incremental_name_filter", size),
+            &source,
+            |b, source| {
+                b.iter(|| {
+                    IncrementalLoader::new()
+                        .name_filter(|name| name.ends_with('7'))
+                        .load(&mut serde_json::Deserializer::from_str(source))
+                        .unwrap()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn genja_filter(c: &mut Criterion) {
+    let genja = Genja::new(inventory(100_000));
+    c.bench_function("genja_filter_100k", |b| {
+        b.iter(|| genja.filter(|host| host.platform.as_deref() == Some("eos")))
+    });
+}
+
+criterion_group!(benches, custom_tree_map, inventory_load, genja_filter);
+criterion_main!(benches);
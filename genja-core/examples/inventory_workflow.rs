@@ -0,0 +1,108 @@
+//! End to end walk through the inventory API against a mock connection.
+//!
+//! Loads a YAML inventory, filters it, opens a connection per selected host
+//! through the `ConnectionManager` and exports the result for other tools.
+//!
+//! ```text
+//! cargo run -p genja-core --example inventory_workflow
+//! ```
+
+use genja_core::inventory::{
+    Connection, ConnectionKey, Diagram, DiagramOptions, Inventory, ResolvedConnectionParams,
+};
+use genja_core::Genja;
+
+const INVENTORY: &str = r#"
+hosts:
+  spine1:
+    name: spine1
+    hostname: 192.0.2.1
+    platform: eos
+    groups: [spines]
+    data: { role: spine, neighbors: [leaf1, leaf2] }
+  leaf1:
+    name: leaf1
+    hostname: 192.0.2.11
+    platform: eos
+    groups: [leaves]
+    data: { role: leaf }
+  leaf2:
+    name: leaf2
+    hostname: 192.0.2.12
+    platform: eos
+    groups: [leaves]
+    data: { role: leaf }
+groups:
+  spines:
+    groups: [dc1]
+  leaves:
+    groups: [dc1]
+defaults:
+  username: automation
+"#;
+
+/// Stand-in for a transport plugin; it only records the parameters it was
+/// opened with.
+#[derive(Debug)]
+struct MockConnection {
+    key: ConnectionKey,
+    target: Option<String>,
+}
+
+impl Connection for MockConnection {
+    fn is_alive(&self) -> bool {
+        self.target.is_some()
+    }
+
+    fn open(&mut self, params: &ResolvedConnectionParams) -> Result<(), String> {
+        self.target = Some(format!("{}:{}", params.hostname, params.port.unwrap_or(22)));
+        Ok(())
+    }
+
+    fn close(&mut self) -> ConnectionKey {
+        self.target = None;
+        self.key.clone()
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let inventory: Inventory = serde_yaml::from_str(INVENTORY)?;
+    let genja = Genja::new(inventory.clone());
+
+    let leaves = genja.filter(|host| {
+        host.data
+            .as_ref()
+            .is_some_and(|data| data["role"] == "leaf")
+    });
+    println!(
+        "selected {} of {} hosts",
+        leaves.host_count(),
+        genja.host_count()
+    );
+
+    for host in leaves.iter_hosts() {
+        let key = ConnectionKey::new(host.name.as_str(), "mock");
+        let connection = inventory
+            .connections
+            .get_or_create(key.clone(), || MockConnection { key, target: None });
+        let mut host = host.clone();
+        let params = host.resolve_connection_params("mock");
+        let mut connection = connection.lock().expect("connection lock poisoned");
+        connection.open(params)?;
+        println!("{host} -> alive={}", connection.is_alive());
+    }
+    inventory.connections.close_all_connections();
+
+    #[cfg(feature = "query")]
+    println!(
+        "spines: {}",
+        inventory.query("hosts[?data.role == 'spine'].name")?
+    );
+
+    println!(
+        "{}",
+        inventory.to_mermaid(Diagram::GroupHierarchy, &DiagramOptions::new())
+    );
+    println!("{:#}", inventory.to_ansible_json()?);
+    Ok(())
+}
@@ -0,0 +1,280 @@
+//! A small assertion layer for post-checks.
+//!
+//! Assertions are evaluated against any `serde_json::Value` (task output,
+//! host `Data`, gathered facts) and produce an [`AssertionResult`] instead of
+//! panicking, so callers can collect structured pass/fail entries.
+//!
+//! # Examples
+//!
+//! ```
+//! use genja_core::assert;
+//! use serde_json::json;
+//!
+//! let output = json!({
+//!     "bgp": {
+//!         "peers": [
+//!             { "address": "192.0.2.1", "state": "Established" },
+//!             { "address": "192.0.2.2", "state": "Established" }
+//!         ]
+//!     }
+//! });
+//!
+//! let result = assert::that(&output)
+//!     .path("bgp.peers[*].state")
+//!     .all_eq("Established");
+//! assert!(result.passed);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+/// Starts an assertion against `value`.
+pub fn that(value: &Value) -> Assertion<'_> {
+    Assertion {
+        value,
+        path: String::new(),
+    }
+}
+
+/// A pending assertion, created with [`that`].
+///
+/// The path defaults to the root of the value. Paths use dot-separated keys
+/// with `[n]` for list indices and `[*]` to fan out over every element of a
+/// list (or every value of an object), e.g. `interfaces[*].oper_status`.
+#[derive(Debug, Clone)]
+pub struct Assertion<'a> {
+    value: &'a Value,
+    path: String,
+}
+
+/// The structured outcome of a single assertion.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssertionResult {
+    pub path: String,
+    pub check: String,
+    pub passed: bool,
+    pub expected: Option<Value>,
+    pub actual: Vec<Value>,
+    pub message: String,
+}
+
+impl fmt::Display for AssertionResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = if self.passed { "PASS" } else { "FAIL" };
+        write!(f, "[{status}] {}: {}", self.check, self.message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+    Wildcard,
+}
+
+impl<'a> Assertion<'a> {
+    /// Sets the path the assertion is evaluated at.
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = path.to_string();
+        self
+    }
+
+    /// Passes when the path resolves to at least one value.
+    pub fn exists(self) -> AssertionResult {
+        self.evaluate("exists", None, |actual| !actual.is_empty())
+    }
+
+    /// Passes when the path resolves to at least one value and every
+    /// resolved value equals `expected`.
+    pub fn all_eq(self, expected: impl Into<Value>) -> AssertionResult {
+        let expected = expected.into();
+        let check = expected.clone();
+        self.evaluate("all_eq", Some(expected), move |actual| {
+            !actual.is_empty() && actual.iter().all(|value| *value == &check)
+        })
+    }
+
+    /// Passes when any resolved value equals `expected`.
+    pub fn any_eq(self, expected: impl Into<Value>) -> AssertionResult {
+        let expected = expected.into();
+        let check = expected.clone();
+        self.evaluate("any_eq", Some(expected), move |actual| {
+            actual.contains(&&check)
+        })
+    }
+
+    /// Passes when no resolved value equals `expected`.
+    pub fn none_eq(self, expected: impl Into<Value>) -> AssertionResult {
+        let expected = expected.into();
+        let check = expected.clone();
+        self.evaluate("none_eq", Some(expected), move |actual| {
+            actual.iter().all(|value| *value != &check)
+        })
+    }
+
+    /// Passes when the path resolves to exactly `count` values.
+    pub fn count(self, count: usize) -> AssertionResult {
+        self.evaluate("count", Some(Value::from(count)), move |actual| {
+            actual.len() == count
+        })
+    }
+
+    fn evaluate<F>(self, check: &str, expected: Option<Value>, pred: F) -> AssertionResult
+    where
+        F: FnOnce(&[&Value]) -> bool,
+    {
+        let segments = match parse_path(&self.path) {
+            Ok(segments) => segments,
+            Err(message) => {
+                return AssertionResult {
+                    path: self.path,
+                    check: check.to_string(),
+                    passed: false,
+                    expected,
+                    actual: Vec::new(),
+                    message,
+                }
+            }
+        };
+
+        let actual = resolve(self.value, &segments);
+        let passed = pred(&actual);
+        let actual: Vec<Value> = actual.into_iter().cloned().collect();
+        let message = match (&expected, passed) {
+            (_, true) => format!("`{}` satisfied {check}", self.path),
+            (Some(expected), false) => {
+                format!(
+                    "`{}` expected {check} {expected}, got {}",
+                    self.path,
+                    Value::from(actual.clone())
+                )
+            }
+            (None, false) => format!("`{}` did not resolve to any value", self.path),
+        };
+
+        AssertionResult {
+            path: self.path,
+            check: check.to_string(),
+            passed,
+            expected,
+            actual,
+            message,
+        }
+    }
+}
+
+fn parse_path(path: &str) -> Result<Vec<Segment>, String> {
+    let mut segments = Vec::new();
+    for part in path.split('.').filter(|part| !part.is_empty()) {
+        let (key, mut rest) = match part.find('[') {
+            Some(pos) => part.split_at(pos),
+            None => (part, ""),
+        };
+        if !key.is_empty() {
+            segments.push(Segment::Key(key.to_string()));
+        }
+        while !rest.is_empty() {
+            let Some(end) = rest.find(']') else {
+                return Err(format!("invalid path `{path}`: unclosed `[`"));
+            };
+            let inner = &rest[1..end];
+            if inner == "*" {
+                segments.push(Segment::Wildcard);
+            } else {
+                let index = inner
+                    .parse()
+                    .map_err(|_| format!("invalid path `{path}`: bad index `{inner}`"))?;
+                segments.push(Segment::Index(index));
+            }
+            rest = &rest[end + 1..];
+            if !rest.is_empty() && !rest.starts_with('[') {
+                return Err(format!("invalid path `{path}`: unexpected `{rest}`"));
+            }
+        }
+    }
+    Ok(segments)
+}
+
+fn resolve<'v>(value: &'v Value, segments: &[Segment]) -> Vec<&'v Value> {
+    let mut current = vec![value];
+    for segment in segments {
+        current = current
+            .into_iter()
+            .flat_map(|value| -> Vec<&'v Value> {
+                match (segment, value) {
+                    (Segment::Key(key), Value::Object(map)) => map.get(key).into_iter().collect(),
+                    (Segment::Index(index), Value::Array(list)) => {
+                        list.get(*index).into_iter().collect()
+                    }
+                    (Segment::Wildcard, Value::Array(list)) => list.iter().collect(),
+                    (Segment::Wildcard, Value::Object(map)) => map.values().collect(),
+                    _ => Vec::new(),
+                }
+            })
+            .collect();
+    }
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn bgp_output() -> Value {
+        json!({
+            "bgp": {
+                "peers": [
+                    { "address": "192.0.2.1", "state": "Established" },
+                    { "address": "192.0.2.2", "state": "Idle" }
+                ]
+            }
+        })
+    }
+
+    #[test]
+    fn test_all_eq_reports_actual_values() {
+        let output = bgp_output();
+        let result = that(&output)
+            .path("bgp.peers[*].state")
+            .all_eq("Established");
+        assert!(!result.passed);
+        assert_eq!(result.actual, vec![json!("Established"), json!("Idle")]);
+    }
+
+    #[test]
+    fn test_index_and_any_eq() {
+        let output = bgp_output();
+        assert!(
+            that(&output)
+                .path("bgp.peers[1].state")
+                .all_eq("Idle")
+                .passed
+        );
+        assert!(
+            that(&output)
+                .path("bgp.peers[*].state")
+                .any_eq("Idle")
+                .passed
+        );
+        assert!(that(&output).path("bgp.peers").count(1).passed);
+        assert!(that(&output).path("bgp.peers[*]").count(2).passed);
+    }
+
+    #[test]
+    fn test_missing_path_fails() {
+        let output = bgp_output();
+        let result = that(&output).path("ospf.neighbors").exists();
+        assert!(!result.passed);
+        assert!(!that(&output).path("bgp.peers[*].asn").all_eq(65000).passed);
+    }
+
+    #[test]
+    fn test_invalid_path_fails_without_panic() {
+        let output = bgp_output();
+        let result = that(&output).path("bgp.peers[x]").exists();
+        assert!(!result.passed);
+        assert!(result.message.contains("bad index"));
+    }
+}
@@ -0,0 +1,17 @@
+//! Connection plugins shipped with genja-core.
+
+mod container;
+mod errors;
+mod ffi;
+#[cfg(feature = "jsonrpc")]
+mod jsonrpc;
+mod local;
+mod process;
+
+pub use container::{ContainerConnection, ContainerOptions};
+pub use errors::{DeviceError, DeviceErrorKind, RetryPolicy};
+pub use ffi::{FfiConnection, FfiConnectionVTable};
+#[cfg(feature = "jsonrpc")]
+pub use jsonrpc::{JsonRpcConnection, JsonRpcOptions};
+pub use local::{LocalConnection, LocalOptions};
+pub use process::CommandOutput;
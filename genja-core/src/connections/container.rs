@@ -0,0 +1,188 @@
+use super::process::{self, CommandOutput};
+use crate::inventory::{
+    Connection, ConnectionKey, PluginOptions, ResolvedConnectionParams, SupportsExec,
+};
+use serde::Deserialize;
+use std::process::Command;
+use std::time::Duration;
+
+/// Options read from `connection_options.container.extras`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContainerOptions {
+    /// Container runtime CLI, e.g. `docker` (default) or `podman`.
+    pub runtime: Option<String>,
+    /// Container name or id. Defaults to the resolved hostname, which for
+    /// containerlab nodes is the `clab-<lab>-<node>` container name.
+    pub container: Option<String>,
+    /// Program and arguments the command is appended to inside the
+    /// container. Defaults to `sh -c`.
+    pub shell: Option<Vec<String>>,
+    /// User to run commands as, passed as `--user`.
+    pub user: Option<String>,
+    /// Default timeout in seconds for commands run through `exec`.
+    pub timeout: Option<u64>,
+}
+
+impl PluginOptions for ContainerOptions {
+    const PLUGIN: &'static str = ContainerConnection::PLUGIN;
+}
+
+/// Runs commands inside a container with `docker exec` or `podman exec`.
+///
+/// Useful for containerized network OS labs and device simulators where
+/// the node is reachable through the container runtime rather than SSH.
+#[derive(Debug, Default)]
+pub struct ContainerConnection {
+    key: Option<ConnectionKey>,
+    container: String,
+    options: ContainerOptions,
+}
+
+impl ContainerConnection {
+    pub const PLUGIN: &'static str = "container";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The runtime command line used to run `command`, without running it.
+    pub fn command_line(&self, command: &str) -> Vec<String> {
+        let mut line = vec![
+            self.options
+                .runtime
+                .clone()
+                .unwrap_or_else(|| "docker".to_string()),
+            "exec".to_string(),
+        ];
+        if let Some(user) = &self.options.user {
+            line.extend(["--user".to_string(), user.clone()]);
+        }
+        line.push(self.container.clone());
+        match &self.options.shell {
+            Some(shell) => line.extend(shell.iter().cloned()),
+            None => line.extend(["sh".to_string(), "-c".to_string()]),
+        }
+        line.push(command.to_string());
+        line
+    }
+
+    /// Runs `command` in the container and captures its output, see
+    /// [`LocalConnection::run`](super::LocalConnection::run).
+    pub fn run(&self, command: &str, timeout: Option<Duration>) -> Result<CommandOutput, String> {
+        if self.key.is_none() {
+            return Err("container connection is not open".to_string());
+        }
+        let line = self.command_line(command);
+        let (program, args) = line.split_first().expect("runtime is always set");
+        process::run(Command::new(program).args(args), timeout)
+    }
+}
+
+impl Connection for ContainerConnection {
+    fn is_alive(&self) -> bool {
+        self.key.is_some()
+    }
+
+    fn open(&mut self, params: &ResolvedConnectionParams) -> Result<(), String> {
+        self.options = params
+            .extras_as::<ContainerOptions>()
+            .map_err(|error| format!("invalid container connection options: {error}"))?
+            .unwrap_or_default();
+        self.container = self
+            .options
+            .container
+            .clone()
+            .unwrap_or_else(|| params.hostname.clone());
+        self.key = Some(ConnectionKey::new(params.hostname.as_str(), Self::PLUGIN));
+        Ok(())
+    }
+
+    fn close(&mut self) -> ConnectionKey {
+        self.key
+            .take()
+            .unwrap_or_else(|| ConnectionKey::new("", Self::PLUGIN))
+    }
+
+    fn as_exec(&mut self) -> Option<&mut (dyn SupportsExec + 'static)> {
+        Some(self)
+    }
+}
+
+impl SupportsExec for ContainerConnection {
+    fn exec(&mut self, command: &str) -> Result<String, String> {
+        let timeout = self.options.timeout.map(Duration::from_secs);
+        self.run(command, timeout)?.into_stdout(command)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::{BaseBuilderHost, ConnectionOptions, Host};
+    use serde_json::json;
+
+    fn open(hostname: &str, extras: serde_json::Value) -> ContainerConnection {
+        let options: ConnectionOptions =
+            serde_json::from_value(json!({ "extras": extras })).unwrap();
+        let mut host = Host::builder("spine1")
+            .hostname(hostname)
+            .connection_options(ContainerConnection::PLUGIN.to_string(), options)
+            .build();
+        let mut connection = ContainerConnection::new();
+        connection
+            .open(host.resolve_connection_params(ContainerConnection::PLUGIN))
+            .unwrap();
+        connection
+    }
+
+    #[test]
+    fn test_command_line() {
+        let connection = open("clab-lab-spine1", json!({}));
+        assert_eq!(
+            connection.command_line("show version"),
+            [
+                "docker",
+                "exec",
+                "clab-lab-spine1",
+                "sh",
+                "-c",
+                "show version"
+            ]
+        );
+
+        let connection = open(
+            "172.20.20.2",
+            json!({
+                "runtime": "podman",
+                "container": "ceos1",
+                "shell": ["Cli", "-c"],
+                "user": "admin"
+            }),
+        );
+        assert_eq!(
+            connection.command_line("show version"),
+            [
+                "podman",
+                "exec",
+                "--user",
+                "admin",
+                "ceos1",
+                "Cli",
+                "-c",
+                "show version"
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_exec_through_runtime() {
+        // `echo` stands in for the runtime and prints the exec arguments.
+        let mut connection = open("ceos1", json!({ "runtime": "echo" }));
+        let output = connection.exec("uptime").unwrap();
+        assert_eq!(output, "exec ceos1 sh -c uptime\n");
+        connection.close();
+        assert!(connection.exec("uptime").is_err());
+    }
+}
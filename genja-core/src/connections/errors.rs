@@ -0,0 +1,330 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::thread;
+use std::time::Duration;
+
+/// What went wrong while talking to a device.
+///
+/// Connection plugins report errors as strings; [`DeviceErrorKind::classify`]
+/// recovers the kind from the usual wording of SSH clients, NETCONF servers
+/// and device CLIs so retries and reports can tell failures apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceErrorKind {
+    /// Credentials or keys were refused.
+    AuthFailure,
+    /// The device did not answer in time.
+    Timeout,
+    /// The device answered but refused the command or configuration.
+    CommandRejected,
+    /// The configuration or datastore is locked by another session.
+    ConfigLocked,
+    /// The device could not be reached.
+    Unreachable,
+    /// The device's output could not be parsed.
+    ParsingError,
+    /// Anything else.
+    Other,
+}
+
+/// Lower-case fragments of error messages, by kind, checked in order.
+const PATTERNS: &[(DeviceErrorKind, &[&str])] = &[
+    (
+        DeviceErrorKind::AuthFailure,
+        &[
+            "authentication failed",
+            "auth fail",
+            "permission denied",
+            "access denied",
+            "login incorrect",
+            "invalid credentials",
+            "bad password",
+        ],
+    ),
+    (
+        DeviceErrorKind::ConfigLocked,
+        &[
+            "lock-denied",
+            "lock denied",
+            "configuration is locked",
+            "config locked",
+            "database is locked",
+            "in-use",
+        ],
+    ),
+    (
+        DeviceErrorKind::Timeout,
+        &["timed out", "timeout", "time out"],
+    ),
+    (
+        DeviceErrorKind::Unreachable,
+        &[
+            "connection refused",
+            "no route to host",
+            "network is unreachable",
+            "host is unreachable",
+            "could not resolve",
+            "name or service not known",
+            "connection reset",
+            "broken pipe",
+        ],
+    ),
+    (
+        DeviceErrorKind::ParsingError,
+        &[
+            "parse error",
+            "failed to parse",
+            "unable to parse",
+            "invalid xml",
+            "invalid json",
+            "unexpected output",
+        ],
+    ),
+    (
+        DeviceErrorKind::CommandRejected,
+        &[
+            "invalid input",
+            "invalid command",
+            "unknown command",
+            "incomplete command",
+            "ambiguous command",
+            "syntax error",
+            "% error",
+            "rpc-error",
+            "commit failed",
+        ],
+    ),
+];
+
+impl DeviceErrorKind {
+    /// Guesses the kind of an error message, [`DeviceErrorKind::Other`]
+    /// when nothing matches.
+    pub fn classify(message: &str) -> Self {
+        let message = message.to_lowercase();
+        PATTERNS
+            .iter()
+            .find(|(_, fragments)| fragments.iter().any(|f| message.contains(f)))
+            .map_or(DeviceErrorKind::Other, |(kind, _)| *kind)
+    }
+
+    /// Returns `true` for transient failures worth another attempt:
+    /// timeouts, unreachable devices and locked configurations. Refused
+    /// credentials are never retried so accounts are not locked out.
+    pub fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            DeviceErrorKind::Timeout | DeviceErrorKind::Unreachable | DeviceErrorKind::ConfigLocked
+        )
+    }
+}
+
+impl fmt::Display for DeviceErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DeviceErrorKind::AuthFailure => "authentication failure",
+            DeviceErrorKind::Timeout => "timeout",
+            DeviceErrorKind::CommandRejected => "command rejected",
+            DeviceErrorKind::ConfigLocked => "configuration locked",
+            DeviceErrorKind::Unreachable => "unreachable",
+            DeviceErrorKind::ParsingError => "parsing error",
+            DeviceErrorKind::Other => "error",
+        })
+    }
+}
+
+/// An error from a device interaction with its [`DeviceErrorKind`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceError {
+    pub kind: DeviceErrorKind,
+    pub message: String,
+}
+
+impl DeviceError {
+    pub fn new(kind: DeviceErrorKind, message: impl Into<String>) -> Self {
+        DeviceError {
+            kind,
+            message: message.into(),
+        }
+    }
+
+    /// Wraps a plugin's error message, classifying it with
+    /// [`DeviceErrorKind::classify`].
+    pub fn classify(message: impl Into<String>) -> Self {
+        let message = message.into();
+        DeviceError {
+            kind: DeviceErrorKind::classify(&message),
+            message,
+        }
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        self.kind.is_retryable()
+    }
+}
+
+impl fmt::Display for DeviceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for DeviceError {}
+
+impl From<String> for DeviceError {
+    fn from(message: String) -> Self {
+        DeviceError::classify(message)
+    }
+}
+
+impl From<DeviceError> for String {
+    fn from(error: DeviceError) -> Self {
+        error.message
+    }
+}
+
+/// How often and how fast a device interaction is retried.
+///
+/// Only [retryable](DeviceErrorKind::is_retryable) errors are retried. The
+/// delay doubles after each attempt, up to `max_delay`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RetryPolicy {
+    /// Total number of attempts, the first one included.
+    pub attempts: u32,
+    /// Seconds to wait before the second attempt.
+    pub delay: f64,
+    /// Upper bound of the delay in seconds.
+    pub max_delay: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            attempts: 3,
+            delay: 1.0,
+            max_delay: 30.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy making a single attempt.
+    pub fn never() -> Self {
+        RetryPolicy {
+            attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// The delay before attempt `attempt + 1`, `attempt` counting from 1.
+    pub fn delay_after(&self, attempt: u32) -> Duration {
+        let delay = self.delay * 2f64.powi(attempt.saturating_sub(1).min(31) as i32);
+        let delay = delay.min(self.max_delay);
+        if delay.is_finite() && delay > 0.0 {
+            Duration::from_secs_f64(delay)
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    /// Runs `operation` until it succeeds, fails with an error that is not
+    /// retryable, or the attempts are used up. The last error is returned.
+    pub fn run<T, E>(
+        &self,
+        mut operation: impl FnMut(u32) -> Result<T, E>,
+    ) -> Result<T, DeviceError>
+    where
+        E: Into<DeviceError>,
+    {
+        let mut attempt = 1;
+        loop {
+            let error = match operation(attempt) {
+                Ok(value) => return Ok(value),
+                Err(error) => error.into(),
+            };
+            if !error.is_retryable() || attempt >= self.attempts {
+                return Err(error);
+            }
+            log::debug!("attempt {attempt} failed with {}: {error}", error.kind);
+            thread::sleep(self.delay_after(attempt));
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_messages() {
+        let cases = [
+            (
+                "Authentication failed for admin",
+                DeviceErrorKind::AuthFailure,
+            ),
+            ("`show version` timed out", DeviceErrorKind::Timeout),
+            (
+                "% Invalid input detected at '^' marker.",
+                DeviceErrorKind::CommandRejected,
+            ),
+            (
+                "<error-tag>lock-denied</error-tag>",
+                DeviceErrorKind::ConfigLocked,
+            ),
+            (
+                "connect to 192.0.2.1 port 22: No route to host",
+                DeviceErrorKind::Unreachable,
+            ),
+            (
+                "failed to parse interface table",
+                DeviceErrorKind::ParsingError,
+            ),
+            ("something odd", DeviceErrorKind::Other),
+        ];
+        for (message, kind) in cases {
+            assert_eq!(DeviceErrorKind::classify(message), kind, "{message}");
+        }
+        assert!(DeviceErrorKind::Timeout.is_retryable());
+        assert!(!DeviceErrorKind::AuthFailure.is_retryable());
+        assert_eq!(
+            serde_json::to_value(DeviceErrorKind::ConfigLocked).unwrap(),
+            "config_locked"
+        );
+    }
+
+    #[test]
+    fn test_retry_policy() {
+        let policy = RetryPolicy {
+            attempts: 3,
+            delay: 0.0,
+            max_delay: 0.0,
+        };
+        let mut calls = 0;
+        let result = policy.run(|attempt| {
+            calls += 1;
+            if attempt < 3 {
+                Err("connection timed out".to_string())
+            } else {
+                Ok(attempt)
+            }
+        });
+        assert_eq!(result, Ok(3));
+        assert_eq!(calls, 3);
+
+        calls = 0;
+        let error = policy
+            .run(|_| -> Result<(), String> {
+                calls += 1;
+                Err("Permission denied (publickey)".to_string())
+            })
+            .unwrap_err();
+        assert_eq!(error.kind, DeviceErrorKind::AuthFailure);
+        assert_eq!(calls, 1);
+
+        let backoff = RetryPolicy::default();
+        assert_eq!(backoff.delay_after(1), Duration::from_secs(1));
+        assert_eq!(backoff.delay_after(3), Duration::from_secs(4));
+        assert_eq!(backoff.delay_after(10), Duration::from_secs(30));
+    }
+}
@@ -0,0 +1,325 @@
+use crate::inventory::{Connection, ConnectionKey, ResolvedConnectionParams, SupportsExec};
+use serde_json::json;
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
+use std::fmt;
+use std::sync::{Mutex, MutexGuard};
+
+/// Callbacks of a connection plugin implemented behind a C ABI, e.g. a
+/// wrapper around a vendor's C transport library.
+///
+/// Every callback gets the opaque `state` pointer given to
+/// [`FfiConnection::new`]. Callbacks returning `c_int` return `0` on
+/// success; on failure they may store an error message in their last
+/// argument. Strings handed back to genja are NUL-terminated, allocated by
+/// the plugin and released with `free_string` once copied.
+///
+/// The matching C declaration is:
+///
+/// ```c
+/// typedef struct {
+///     int  (*open)(void *state, const char *params_json, char **error);
+///     bool (*is_alive)(void *state);
+///     void (*close)(void *state);
+///     int  (*exec)(void *state, const char *command, char **output); /* nullable */
+///     void (*free_string)(char *string);
+///     void (*destroy)(void *state);
+/// } genja_connection_vtable;
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct FfiConnectionVTable {
+    /// Opens the connection. `params_json` holds the resolved connection
+    /// parameters as a JSON object, see [`FfiConnection::params_json`].
+    pub open: unsafe extern "C" fn(
+        state: *mut c_void,
+        params_json: *const c_char,
+        error: *mut *mut c_char,
+    ) -> c_int,
+    pub is_alive: unsafe extern "C" fn(state: *mut c_void) -> bool,
+    pub close: unsafe extern "C" fn(state: *mut c_void),
+    /// Runs a command and stores its output, or its error on failure.
+    /// `None` if the plugin does not support [`SupportsExec`].
+    pub exec: Option<
+        unsafe extern "C" fn(
+            state: *mut c_void,
+            command: *const c_char,
+            output: *mut *mut c_char,
+        ) -> c_int,
+    >,
+    pub free_string: unsafe extern "C" fn(string: *mut c_char),
+    /// Releases `state`. Called once when the connection is dropped.
+    pub destroy: unsafe extern "C" fn(state: *mut c_void),
+}
+
+/// A [`Connection`] forwarding to a plugin behind [`FfiConnectionVTable`].
+pub struct FfiConnection {
+    plugin: String,
+    state: Mutex<PluginState>,
+    /// The address of the state, so `Debug` can print it without taking
+    /// the lock while a call is running.
+    address: usize,
+    vtable: FfiConnectionVTable,
+    key: Option<ConnectionKey>,
+}
+
+/// The plugin's opaque state. Every call into the plugin holds the lock
+/// around it, including `is_alive` which only gets `&self`.
+struct PluginState(*mut c_void);
+
+// SAFETY: `FfiConnection::new` requires the plugin state to be usable from
+// any thread, one call at a time. The state is only reached through the
+// `Mutex` in `FfiConnection`, which provides the one call at a time, so
+// `FfiConnection` is `Sync` without further guarantees.
+unsafe impl Send for PluginState {}
+
+impl fmt::Debug for FfiConnection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FfiConnection")
+            .field("plugin", &self.plugin)
+            .field("state", &(self.address as *const c_void))
+            .field("key", &self.key)
+            .finish()
+    }
+}
+
+impl FfiConnection {
+    /// Wraps the plugin `state` registered as `plugin`.
+    ///
+    /// # Safety
+    ///
+    /// The callbacks of `vtable` must be valid to call with `state` until
+    /// `destroy` is called, from any thread, one call at a time, and must
+    /// follow the string conventions of [`FfiConnectionVTable`].
+    pub unsafe fn new(plugin: &str, state: *mut c_void, vtable: FfiConnectionVTable) -> Self {
+        FfiConnection {
+            plugin: plugin.to_string(),
+            state: Mutex::new(PluginState(state)),
+            address: state as usize,
+            vtable,
+            key: None,
+        }
+    }
+
+    /// Locks the plugin state for one call. A panic in a previous call
+    /// leaves the state as the plugin left it, so poisoning is ignored.
+    fn lock(&self) -> MutexGuard<'_, PluginState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// The JSON object passed to `open`. Unset fields are `null`.
+    pub fn params_json(params: &ResolvedConnectionParams) -> String {
+        json!({
+            "hostname": params.hostname,
+            "port": params.port,
+            "username": params.username,
+            "password": params.password,
+            "platform": params.platform,
+            "extras": params.extras.as_deref(),
+            "credentials": params.credentials,
+            "tls": params.tls,
+            "proxy": params.proxy,
+        })
+        .to_string()
+    }
+
+    /// Copies and frees a string handed back by the plugin.
+    fn take_string(&self, string: *mut c_char) -> Option<String> {
+        if string.is_null() {
+            return None;
+        }
+        // SAFETY: non-null strings returned by the plugin are NUL-terminated
+        // and owned by us until passed to `free_string`.
+        unsafe {
+            let copy = CStr::from_ptr(string).to_string_lossy().into_owned();
+            (self.vtable.free_string)(string);
+            Some(copy)
+        }
+    }
+
+    fn failure(&self, call: &str, code: c_int, message: *mut c_char) -> String {
+        match self.take_string(message) {
+            Some(message) => format!("{} {call} failed: {message}", self.plugin),
+            None => format!("{} {call} failed with code {code}", self.plugin),
+        }
+    }
+}
+
+impl Drop for FfiConnection {
+    fn drop(&mut self) {
+        // SAFETY: see `FfiConnection::new`; `destroy` is called exactly once.
+        let state = self.state.get_mut().unwrap_or_else(|e| e.into_inner());
+        unsafe { (self.vtable.destroy)(state.0) }
+    }
+}
+
+fn c_string(value: &str) -> Result<CString, String> {
+    CString::new(value).map_err(|_| format!("`{value}` contains a NUL byte"))
+}
+
+impl Connection for FfiConnection {
+    fn is_alive(&self) -> bool {
+        if self.key.is_none() {
+            return false;
+        }
+        let state = self.lock();
+        // SAFETY: see `FfiConnection::new`; the lock is held for the call.
+        unsafe { (self.vtable.is_alive)(state.0) }
+    }
+
+    fn open(&mut self, params: &ResolvedConnectionParams) -> Result<(), String> {
+        let params_json = c_string(&Self::params_json(params))?;
+        let mut error = std::ptr::null_mut();
+        // SAFETY: see `FfiConnection::new`; `params_json` outlives the call.
+        let code = unsafe { (self.vtable.open)(self.lock().0, params_json.as_ptr(), &mut error) };
+        if code != 0 {
+            return Err(self.failure("open", code, error));
+        }
+        self.key = Some(ConnectionKey::new(
+            params.hostname.as_str(),
+            self.plugin.as_str(),
+        ));
+        Ok(())
+    }
+
+    fn close(&mut self) -> ConnectionKey {
+        match self.key.take() {
+            Some(key) => {
+                // SAFETY: see `FfiConnection::new`.
+                unsafe { (self.vtable.close)(self.lock().0) };
+                key
+            }
+            None => ConnectionKey::new("", self.plugin.as_str()),
+        }
+    }
+
+    fn as_exec(&mut self) -> Option<&mut (dyn SupportsExec + 'static)> {
+        self.vtable.exec.map(|_| self as &mut dyn SupportsExec)
+    }
+}
+
+impl SupportsExec for FfiConnection {
+    fn exec(&mut self, command: &str) -> Result<String, String> {
+        let exec = self
+            .vtable
+            .exec
+            .ok_or_else(|| format!("{} does not support exec", self.plugin))?;
+        let command = c_string(command)?;
+        let mut output = std::ptr::null_mut();
+        // SAFETY: see `FfiConnection::new`; `command` outlives the call.
+        let code = unsafe { exec(self.lock().0, command.as_ptr(), &mut output) };
+        if code != 0 {
+            return Err(self.failure("exec", code, output));
+        }
+        Ok(self.take_string(output).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::{BaseBuilderHost, Host};
+
+    /// A plugin written against the C ABI, as a vendor SDK wrapper would be.
+    #[derive(Default)]
+    struct Device {
+        hostname: Option<String>,
+    }
+
+    fn to_c(value: &str) -> *mut c_char {
+        CString::new(value).unwrap().into_raw()
+    }
+
+    unsafe extern "C" fn open(
+        state: *mut c_void,
+        params_json: *const c_char,
+        error: *mut *mut c_char,
+    ) -> c_int {
+        let device = &mut *(state as *mut Device);
+        let params: serde_json::Value =
+            serde_json::from_slice(CStr::from_ptr(params_json).to_bytes()).unwrap();
+        if params["username"].is_null() {
+            *error = to_c("username required");
+            return 1;
+        }
+        device.hostname = params["hostname"].as_str().map(str::to_string);
+        0
+    }
+
+    unsafe extern "C" fn is_alive(state: *mut c_void) -> bool {
+        (*(state as *mut Device)).hostname.is_some()
+    }
+
+    unsafe extern "C" fn close(state: *mut c_void) {
+        (*(state as *mut Device)).hostname = None;
+    }
+
+    unsafe extern "C" fn exec(
+        state: *mut c_void,
+        command: *const c_char,
+        output: *mut *mut c_char,
+    ) -> c_int {
+        let device = &*(state as *mut Device);
+        let command = CStr::from_ptr(command).to_str().unwrap();
+        *output = to_c(&format!(
+            "{}# {command}",
+            device.hostname.as_deref().unwrap()
+        ));
+        0
+    }
+
+    unsafe extern "C" fn free_string(string: *mut c_char) {
+        drop(CString::from_raw(string));
+    }
+
+    unsafe extern "C" fn destroy(state: *mut c_void) {
+        drop(Box::from_raw(state as *mut Device));
+    }
+
+    fn connection() -> FfiConnection {
+        let vtable = FfiConnectionVTable {
+            open,
+            is_alive,
+            close,
+            exec: Some(exec),
+            free_string,
+            destroy,
+        };
+        let state = Box::into_raw(Box::<Device>::default()) as *mut c_void;
+        unsafe { FfiConnection::new("vendor", state, vtable) }
+    }
+
+    #[test]
+    fn test_ffi_connection_round_trip() {
+        let mut host = Host::builder("r1").hostname("192.0.2.1").build();
+        let mut connection = connection();
+        let error = connection
+            .open(host.resolve_connection_params("vendor"))
+            .unwrap_err();
+        assert_eq!(error, "vendor open failed: username required");
+        assert!(!connection.is_alive());
+
+        let mut host = Host::builder("r1")
+            .hostname("192.0.2.1")
+            .username("admin")
+            .build();
+        connection
+            .open(host.resolve_connection_params("vendor"))
+            .unwrap();
+        assert!(connection.is_alive());
+        let output = connection.as_exec().unwrap().exec("show version");
+        assert_eq!(output.unwrap(), "192.0.2.1# show version");
+        assert_eq!(
+            connection.close(),
+            ConnectionKey::new("192.0.2.1", "vendor")
+        );
+        assert!(!connection.is_alive());
+    }
+
+    #[test]
+    fn test_ffi_connection_debug_does_not_lock() {
+        let connection = connection();
+        let _guard = connection.lock();
+        let debug = format!("{connection:?}");
+        assert!(debug.starts_with("FfiConnection { plugin: \"vendor\", state: 0x"));
+    }
+}
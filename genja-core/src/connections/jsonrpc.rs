@@ -0,0 +1,420 @@
+use super::process;
+use crate::inventory::{
+    Connection, ConnectionKey, PluginOptions, ProxyConfig, ResolvedConnectionParams, SupportsExec,
+    TlsConfig,
+};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::process::Command;
+use std::time::Duration;
+
+/// Options read from `connection_options.jsonrpc.extras`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JsonRpcOptions {
+    /// `https` (default) or `http`.
+    pub scheme: Option<String>,
+    /// Path of the JSON-RPC endpoint. Defaults to `/jsonrpc`.
+    pub path: Option<String>,
+    /// Method called by `exec`. Defaults to `cli`, which takes
+    /// `{"commands": [...]}` on SR OS MD-CLI and SR Linux.
+    pub cli_method: Option<String>,
+    /// HTTP client CLI. Defaults to `curl`.
+    pub client: Option<String>,
+    /// Timeout in seconds of each call.
+    pub timeout: Option<u64>,
+}
+
+impl PluginOptions for JsonRpcOptions {
+    const PLUGIN: &'static str = JsonRpcConnection::PLUGIN;
+}
+
+/// Calls JSON-RPC 2.0 methods over HTTPS, for platforms whose preferred API
+/// is JSON-RPC, such as Nokia SR OS MD-CLI and SR Linux.
+///
+/// Requests are sent with the system `curl`. TLS settings come from the
+/// host's [`TlsConfig`] and the proxy from its [`ProxyConfig`]; the
+/// credentials and request body are passed on stdin rather than on the
+/// command line. The connection is stateless, so reuse through the
+/// [`ConnectionManager`](crate::inventory::ConnectionManager) only keeps the
+/// resolved settings and the request ids.
+#[derive(Debug, Default)]
+pub struct JsonRpcConnection {
+    key: Option<ConnectionKey>,
+    hostname: String,
+    port: Option<u16>,
+    username: Option<String>,
+    password: Option<String>,
+    tls: TlsConfig,
+    proxy: Option<ProxyConfig>,
+    options: JsonRpcOptions,
+    next_id: u64,
+}
+
+impl JsonRpcConnection {
+    pub const PLUGIN: &'static str = "jsonrpc";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn scheme(&self) -> &str {
+        self.options.scheme.as_deref().unwrap_or("https")
+    }
+
+    /// The endpoint URL, addressed by the TLS server name when one is set.
+    pub fn url(&self) -> String {
+        let host = self.tls.server_name_for(&self.hostname);
+        let mut url = format!("{}://{}", self.scheme(), bracketed(host));
+        if let Some(port) = self.port {
+            url.push_str(&format!(":{port}"));
+        }
+        let path = self.options.path.as_deref().unwrap_or("/jsonrpc");
+        if !path.starts_with('/') {
+            url.push('/');
+        }
+        url.push_str(path);
+        url
+    }
+
+    /// The client command line of a call, without running it. Secrets and
+    /// the request body are not part of it, see [`JsonRpcConnection::call`].
+    pub fn command_line(&self) -> Vec<String> {
+        let mut line: Vec<String> = [
+            self.options.client.as_deref().unwrap_or("curl"),
+            "--silent",
+            "--show-error",
+            "--config",
+            "-",
+            "--header",
+            "Content-Type: application/json",
+            "--write-out",
+            "\\n%{http_code}",
+        ]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+        if let Some(timeout) = self.options.timeout {
+            line.extend(["--max-time".to_string(), timeout.to_string()]);
+        }
+        if !self.tls.verifies() {
+            line.push("--insecure".to_string());
+        }
+        for (flag, path) in [
+            ("--cacert", &self.tls.ca_bundle),
+            ("--cert", &self.tls.client_cert),
+            ("--key", &self.tls.client_key),
+        ] {
+            if let Some(path) = path {
+                line.extend([flag.to_string(), path.clone()]);
+            }
+        }
+        if let Some(name) = &self.tls.server_name {
+            // Resolve the server name to the host's address.
+            line.extend([
+                "--connect-to".to_string(),
+                format!("{}::{}:", bracketed(name), bracketed(&self.hostname)),
+            ]);
+        }
+        if let Some(proxy) = &self.proxy {
+            match proxy.http_proxy_for(self.scheme(), &self.hostname) {
+                Some(url) => line.extend(["--proxy".to_string(), url.to_string()]),
+                None => line.extend(["--noproxy".to_string(), "*".to_string()]),
+            }
+        }
+        line.push(self.url());
+        line
+    }
+
+    /// The JSON-RPC 2.0 request object of a call.
+    pub fn request(id: u64, method: &str, params: Value) -> Value {
+        let mut request = json!({ "jsonrpc": "2.0", "id": id, "method": method });
+        if !params.is_null() {
+            request["params"] = params;
+        }
+        request
+    }
+
+    /// Calls `method` with `params`, `null` to omit them, and returns the
+    /// `result` of the response.
+    ///
+    /// JSON-RPC errors are returned as `JSON-RPC error <code>: <message>`.
+    pub fn call(&mut self, method: &str, params: Value) -> Result<Value, String> {
+        if self.key.is_none() {
+            return Err("jsonrpc connection is not open".to_string());
+        }
+        self.next_id += 1;
+        let request = Self::request(self.next_id, method, params);
+        let mut config = format!("data-binary = {}\n", quoted(&request.to_string()));
+        if let Some(username) = &self.username {
+            let password = self.password.as_deref().unwrap_or_default();
+            config.push_str(&format!(
+                "user = {}\n",
+                quoted(&format!("{username}:{password}"))
+            ));
+        }
+
+        let line = self.command_line();
+        let (program, args) = line.split_first().expect("client is always set");
+        let timeout = self.options.timeout.map(Duration::from_secs);
+        let output = process::run_with_input(
+            Command::new(program).args(args),
+            Some(config.into_bytes()),
+            timeout,
+        )?
+        .into_stdout(method)?;
+        parse_response(self.next_id, &output)
+    }
+
+    /// Calls `method` and deserializes its result into `T`.
+    pub fn call_as<T: DeserializeOwned>(
+        &mut self,
+        method: &str,
+        params: Value,
+    ) -> Result<T, String> {
+        let result = self.call(method, params)?;
+        T::deserialize(result)
+            .map_err(|error| format!("failed to parse `{method}` result: {error}"))
+    }
+}
+
+/// Wraps IPv6 addresses in brackets for use in a URL.
+fn bracketed(host: &str) -> String {
+    if host.contains(':') {
+        format!("[{host}]")
+    } else {
+        host.to_string()
+    }
+}
+
+/// Quotes `value` for a curl config file, where a line break would end the
+/// option.
+fn quoted(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '\\' => quoted.push_str("\\\\"),
+            '"' => quoted.push_str("\\\""),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Parses the client output, the response body followed by a line with the
+/// HTTP status, into the `result` of the response to request `id`.
+fn parse_response(id: u64, output: &str) -> Result<Value, String> {
+    let (body, status) = output.rsplit_once('\n').unwrap_or(("", output));
+    match status.trim().parse::<u16>() {
+        Ok(401 | 403) => return Err(format!("authentication failed (HTTP {})", status.trim())),
+        Ok(200..=299) => {}
+        // JSON-RPC servers may report errors with a non-2xx status and an
+        // error object, which is more useful than the status.
+        Ok(code) if serde_json::from_str::<Value>(body).is_err() => {
+            return Err(format!("HTTP {code}: {}", body.trim()));
+        }
+        Ok(_) => {}
+        Err(_) => return Err(format!("unexpected output: {}", output.trim())),
+    }
+    let response: Value = serde_json::from_str(body)
+        .map_err(|error| format!("invalid json in JSON-RPC response: {error}"))?;
+    if let Some(error) = response.get("error") {
+        let code = error
+            .get("code")
+            .and_then(Value::as_i64)
+            .unwrap_or_default();
+        let message = error
+            .get("message")
+            .and_then(Value::as_str)
+            .unwrap_or("unknown error");
+        return Err(format!("JSON-RPC error {code}: {message}"));
+    }
+    if response.get("id").and_then(Value::as_u64) != Some(id) {
+        return Err(format!("JSON-RPC response does not match request {id}"));
+    }
+    response
+        .get("result")
+        .cloned()
+        .ok_or_else(|| "JSON-RPC response has no result".to_string())
+}
+
+impl Connection for JsonRpcConnection {
+    fn is_alive(&self) -> bool {
+        self.key.is_some()
+    }
+
+    fn open(&mut self, params: &ResolvedConnectionParams) -> Result<(), String> {
+        self.options = params
+            .extras_as::<JsonRpcOptions>()
+            .map_err(|error| format!("invalid jsonrpc connection options: {error}"))?
+            .unwrap_or_default();
+        if !matches!(self.scheme(), "https" | "http") {
+            return Err(format!("unsupported jsonrpc scheme `{}`", self.scheme()));
+        }
+        self.hostname = params.hostname.clone();
+        self.port = params.port;
+        self.username = params.username.clone();
+        self.password = params.password.clone();
+        self.tls = params.tls.clone().unwrap_or_default();
+        self.proxy = params.proxy.clone();
+        self.key = Some(ConnectionKey::new(params.hostname.as_str(), Self::PLUGIN));
+        Ok(())
+    }
+
+    fn close(&mut self) -> ConnectionKey {
+        self.password = None;
+        self.key
+            .take()
+            .unwrap_or_else(|| ConnectionKey::new("", Self::PLUGIN))
+    }
+
+    fn as_exec(&mut self) -> Option<&mut (dyn SupportsExec + 'static)> {
+        Some(self)
+    }
+}
+
+impl SupportsExec for JsonRpcConnection {
+    /// Runs a CLI command through the `cli_method` call. A string result is
+    /// returned as is, anything else as JSON.
+    fn exec(&mut self, command: &str) -> Result<String, String> {
+        let method = self
+            .options
+            .cli_method
+            .clone()
+            .unwrap_or_else(|| "cli".to_string());
+        match self.call(&method, json!({ "commands": [command] }))? {
+            Value::String(output) => Ok(output),
+            result => Ok(result.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::{BaseBuilderHost, ConnectionOptions, Host};
+
+    fn open(hostname: &str, extras: Value, tls: Option<TlsConfig>) -> JsonRpcConnection {
+        let options: ConnectionOptions = serde_json::from_value(json!({
+            "extras": extras,
+            "tls": tls,
+            "proxy": ProxyConfig::new().https("http://proxy:3128"),
+        }))
+        .unwrap();
+        let mut host = Host::builder("sr1")
+            .hostname(hostname)
+            .username("admin")
+            .password("se\"cret")
+            .connection_options(JsonRpcConnection::PLUGIN.to_string(), options)
+            .build();
+        let mut connection = JsonRpcConnection::new();
+        connection
+            .open(host.resolve_connection_params(JsonRpcConnection::PLUGIN))
+            .unwrap();
+        connection
+    }
+
+    #[test]
+    fn test_command_line() {
+        let tls = TlsConfig::new()
+            .ca_bundle("/etc/lab-ca.pem")
+            .verify(false)
+            .server_name("sr1.lab");
+        let connection = open(
+            "2001:db8::1",
+            json!({ "path": "json-rpc", "timeout": 5 }),
+            Some(tls),
+        );
+        assert_eq!(connection.url(), "https://sr1.lab/json-rpc");
+        let line = connection.command_line();
+        assert_eq!(line[0], "curl");
+        for args in [
+            ["--max-time", "5"],
+            ["--cacert", "/etc/lab-ca.pem"],
+            ["--connect-to", "sr1.lab::[2001:db8::1]:"],
+            ["--proxy", "http://proxy:3128"],
+        ] {
+            assert!(line.windows(2).any(|pair| pair == args), "{args:?}");
+        }
+        assert!(line.contains(&"--insecure".to_string()));
+        assert!(!line.iter().any(|arg| arg.contains("cret")));
+
+        let connection = open("10.0.0.1", json!({ "scheme": "http" }), None);
+        assert_eq!(connection.url(), "http://10.0.0.1/jsonrpc");
+        assert!(connection.command_line().ends_with(&[
+            "--noproxy".to_string(),
+            "*".to_string(),
+            "http://10.0.0.1/jsonrpc".to_string()
+        ]));
+    }
+
+    #[test]
+    fn test_quoted() {
+        assert_eq!(quoted("a\"b\\c"), r#""a\"b\\c""#);
+        assert_eq!(quoted("pass\nword\r\t"), r#""pass\nword\r\t""#);
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let ok = "{\"jsonrpc\":\"2.0\",\"id\":3,\"result\":[{\"uptime\":42}]}\n200";
+        assert_eq!(parse_response(3, ok).unwrap(), json!([{ "uptime": 42 }]));
+        assert!(parse_response(4, ok)
+            .unwrap_err()
+            .contains("does not match"));
+
+        let rejected = "{\"jsonrpc\":\"2.0\",\"id\":1,\"error\":{\"code\":-32602,\"message\":\"invalid params\"}}\n500";
+        assert_eq!(
+            parse_response(1, rejected).unwrap_err(),
+            "JSON-RPC error -32602: invalid params"
+        );
+        assert_eq!(
+            parse_response(1, "Unauthorized\n401").unwrap_err(),
+            "authentication failed (HTTP 401)"
+        );
+        assert_eq!(
+            parse_response(1, "<html>not found</html>\n404").unwrap_err(),
+            "HTTP 404: <html>not found</html>"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_call_through_client() {
+        use crate::test_util::TempDir;
+        use std::os::unix::fs::PermissionsExt;
+
+        // Stands in for curl: saves the config read from stdin and answers
+        // the first request.
+        let dir = TempDir::new("jsonrpc-client");
+        let client = dir.write(
+            "client",
+            &format!(
+                "#!/bin/sh\ncat > {}\nprintf '{{\"jsonrpc\":\"2.0\",\"id\":1,\"result\":\"ok\"}}\\n200'\n",
+                dir.join("config").display(),
+            ),
+        );
+        std::fs::set_permissions(&client, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let mut connection = open(
+            "10.0.0.1",
+            json!({ "client": client.display().to_string() }),
+            None,
+        );
+        assert_eq!(connection.exec("show uptime").unwrap(), "ok");
+        let config = std::fs::read_to_string(dir.join("config")).unwrap();
+        assert_eq!(
+            config,
+            "data-binary = \"{\\\"id\\\":1,\\\"jsonrpc\\\":\\\"2.0\\\",\\\"method\\\":\\\"cli\\\",\\\"params\\\":{\\\"commands\\\":[\\\"show uptime\\\"]}}\"\n\
+             user = \"admin:se\\\"cret\"\n"
+        );
+
+        connection.close();
+        assert!(connection.call("get", Value::Null).is_err());
+    }
+}
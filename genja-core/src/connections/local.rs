@@ -0,0 +1,170 @@
+use super::process::{self, CommandOutput};
+use crate::inventory::{
+    Connection, ConnectionKey, PluginOptions, ResolvedConnectionParams, SupportsExec,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::process::Command;
+use std::time::Duration;
+
+/// Options read from `connection_options.local.extras`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LocalOptions {
+    /// Program and arguments the command is appended to. Defaults to
+    /// `sh -c` (`cmd /C` on Windows).
+    pub shell: Option<Vec<String>>,
+    /// Default timeout in seconds for commands run through `exec`.
+    pub timeout: Option<u64>,
+    /// Extra environment variables for every command.
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+}
+
+impl PluginOptions for LocalOptions {
+    const PLUGIN: &'static str = LocalConnection::PLUGIN;
+}
+
+/// Runs commands as subprocesses on the controller.
+///
+/// The resolved hostname is exported to each command as `GENJA_HOST`, so a
+/// local script can act on behalf of a host, e.g. by calling a vendor CLI.
+#[derive(Debug, Default)]
+pub struct LocalConnection {
+    key: Option<ConnectionKey>,
+    options: LocalOptions,
+}
+
+impl LocalConnection {
+    pub const PLUGIN: &'static str = "local";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn shell(&self) -> Vec<String> {
+        self.options.shell.clone().unwrap_or_else(|| {
+            let shell: &[&str] = if cfg!(windows) {
+                &["cmd", "/C"]
+            } else {
+                &["sh", "-c"]
+            };
+            shell.iter().map(|s| s.to_string()).collect()
+        })
+    }
+
+    /// Runs `command` through the configured shell and captures its output.
+    ///
+    /// The process is killed once `timeout` elapses, in which case the
+    /// output collected so far is returned with `timed_out` set.
+    pub fn run(&self, command: &str, timeout: Option<Duration>) -> Result<CommandOutput, String> {
+        let key = self.key.as_ref().ok_or("local connection is not open")?;
+        let shell = self.shell();
+        let (program, args) = shell.split_first().ok_or("local shell is empty")?;
+
+        process::run(
+            Command::new(program)
+                .args(args)
+                .arg(command)
+                .envs(&self.options.env)
+                .env("GENJA_HOST", &key.hostname),
+            timeout,
+        )
+    }
+}
+
+impl Connection for LocalConnection {
+    fn is_alive(&self) -> bool {
+        self.key.is_some()
+    }
+
+    fn open(&mut self, params: &ResolvedConnectionParams) -> Result<(), String> {
+        self.options = params
+            .extras_as::<LocalOptions>()
+            .map_err(|error| format!("invalid local connection options: {error}"))?
+            .unwrap_or_default();
+        self.key = Some(ConnectionKey::new(params.hostname.as_str(), Self::PLUGIN));
+        Ok(())
+    }
+
+    fn close(&mut self) -> ConnectionKey {
+        self.key
+            .take()
+            .unwrap_or_else(|| ConnectionKey::new("", Self::PLUGIN))
+    }
+
+    fn as_exec(&mut self) -> Option<&mut (dyn SupportsExec + 'static)> {
+        Some(self)
+    }
+}
+
+/// Returns stdout, or an error with stderr when the command fails or times
+/// out. The timeout comes from [`LocalOptions::timeout`].
+impl SupportsExec for LocalConnection {
+    fn exec(&mut self, command: &str) -> Result<String, String> {
+        let timeout = self.options.timeout.map(Duration::from_secs);
+        self.run(command, timeout)?.into_stdout(command)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::inventory::{BaseBuilderHost, ConnectionOptions, Host};
+    use serde_json::json;
+    use std::time::Instant;
+
+    fn open(extras: serde_json::Value) -> LocalConnection {
+        let options: ConnectionOptions =
+            serde_json::from_value(json!({ "extras": extras })).unwrap();
+        let mut host = Host::builder("r1")
+            .hostname("192.0.2.1")
+            .connection_options(LocalConnection::PLUGIN.to_string(), options)
+            .build();
+        let mut connection = LocalConnection::new();
+        connection
+            .open(host.resolve_connection_params(LocalConnection::PLUGIN))
+            .unwrap();
+        connection
+    }
+
+    #[test]
+    fn test_exec_captures_stdout_and_env() {
+        let mut connection = open(json!({ "env": { "SITE": "hq" } }));
+        assert!(connection.is_alive());
+        let output = connection.exec("echo $GENJA_HOST $SITE").unwrap();
+        assert_eq!(output, "192.0.2.1 hq\n");
+    }
+
+    #[test]
+    fn test_failure_reports_exit_code_and_stderr() {
+        let connection = open(json!({}));
+        let output = connection.run("echo oops >&2; exit 3", None).unwrap();
+        assert_eq!(output.exit_code, Some(3));
+        assert_eq!(output.stderr, "oops\n");
+
+        let mut connection = connection;
+        let error = connection.exec("echo oops >&2; exit 3").unwrap_err();
+        assert_eq!(error, "`echo oops >&2; exit 3` exited with 3: oops");
+    }
+
+    #[test]
+    fn test_timeout_kills_the_process() {
+        let connection = open(json!({}));
+        let started = Instant::now();
+        let output = connection
+            .run("echo started; sleep 5", Some(Duration::from_millis(200)))
+            .unwrap();
+        assert!(output.timed_out);
+        assert_eq!(output.exit_code, None);
+        assert_eq!(output.stdout, "started\n");
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+
+    #[test]
+    fn test_closed_connection_refuses_commands() {
+        let mut connection = open(json!({}));
+        assert_eq!(connection.close(), ConnectionKey::new("192.0.2.1", "local"));
+        assert!(connection.run("true", None).is_err());
+    }
+}
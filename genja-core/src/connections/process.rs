@@ -0,0 +1,177 @@
+use std::io::{Read, Write};
+use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Output of a command run by a subprocess based connection plugin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandOutput {
+    pub stdout: String,
+    pub stderr: String,
+    /// `None` when the process was killed by a signal or timed out.
+    pub exit_code: Option<i32>,
+    pub timed_out: bool,
+}
+
+impl CommandOutput {
+    pub fn success(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+
+    /// Returns stdout, or an error with stderr when the command failed or
+    /// timed out. `command` is only used in the error message.
+    pub fn into_stdout(self, command: &str) -> Result<String, String> {
+        if self.timed_out {
+            return Err(format!("`{command}` timed out"));
+        }
+        if !self.success() {
+            let code = self
+                .exit_code
+                .map_or_else(|| "signal".to_string(), |code| code.to_string());
+            return Err(format!(
+                "`{command}` exited with {code}: {}",
+                self.stderr.trim_end()
+            ));
+        }
+        Ok(self.stdout)
+    }
+}
+
+/// Reads a child's pipe on its own thread so a chatty process never blocks
+/// on a full pipe while we wait for it.
+struct Pipe {
+    buffer: Arc<Mutex<Vec<u8>>>,
+    reader: thread::JoinHandle<()>,
+}
+
+impl Pipe {
+    fn drain(pipe: Option<impl Read + Send + 'static>) -> Pipe {
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let shared = Arc::clone(&buffer);
+        let reader = thread::spawn(move || {
+            let Some(mut pipe) = pipe else {
+                return;
+            };
+            let mut chunk = [0; 8192];
+            while let Ok(read @ 1..) = pipe.read(&mut chunk) {
+                shared
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .extend_from_slice(&chunk[..read]);
+            }
+        });
+        Pipe { buffer, reader }
+    }
+
+    /// Returns the output once the pipe closes, or once `grace` elapses when
+    /// set, e.g. because a process outside the killed group still holds it.
+    fn finish(self, grace: Option<Duration>) -> String {
+        match grace {
+            None => {
+                let _ = self.reader.join();
+            }
+            Some(grace) => {
+                let deadline = Instant::now() + grace;
+                while !self.reader.is_finished() && Instant::now() < deadline {
+                    thread::sleep(Duration::from_millis(5));
+                }
+            }
+        }
+        let buffer = self.buffer.lock().unwrap_or_else(|e| e.into_inner());
+        String::from_utf8_lossy(&buffer).into_owned()
+    }
+}
+
+/// How long the pipe readers are given to collect the remaining output of a
+/// killed process.
+const KILL_GRACE: Duration = Duration::from_millis(500);
+
+#[cfg(unix)]
+extern "C" {
+    fn kill(pid: i32, signal: i32) -> i32;
+}
+
+/// Kills `child` and, on Unix, the rest of its process group, so commands
+/// started by a shell die with it.
+fn kill_tree(child: &mut Child) {
+    #[cfg(unix)]
+    if let Ok(pid) = i32::try_from(child.id()) {
+        const SIGKILL: i32 = 9;
+        // SAFETY: `kill` has no memory safety requirements. The child leads
+        // its own process group, see `run_with_input`, and is not reaped
+        // yet, so the group id cannot have been reused.
+        unsafe {
+            kill(-pid, SIGKILL);
+        }
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Spawns `command` and captures its output, killing it and the processes
+/// it started once `timeout` elapses. After a timeout the output written
+/// before the kill is returned with `timed_out` set.
+pub(crate) fn run(
+    command: &mut Command,
+    timeout: Option<Duration>,
+) -> Result<CommandOutput, String> {
+    run_with_input(command, None, timeout)
+}
+
+/// Like [`run`], writing `input` to the child's stdin instead of leaving it
+/// closed. Used to hand secrets to a program without putting them in its
+/// arguments.
+pub(crate) fn run_with_input(
+    command: &mut Command,
+    input: Option<Vec<u8>>,
+    timeout: Option<Duration>,
+) -> Result<CommandOutput, String> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let stdin = if input.is_some() {
+        Stdio::piped()
+    } else {
+        Stdio::null()
+    };
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(command, 0);
+    let mut child = command
+        .stdin(stdin)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| format!("failed to spawn `{program}`: {error}"))?;
+
+    // Written on its own thread so a child that answers before reading all
+    // of its input cannot deadlock with us.
+    if let (Some(input), Some(mut pipe)) = (input, child.stdin.take()) {
+        thread::spawn(move || {
+            let _ = pipe.write_all(&input);
+        });
+    }
+
+    let stdout = Pipe::drain(child.stdout.take());
+    let stderr = Pipe::drain(child.stderr.take());
+
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    let mut timed_out = false;
+    let status = loop {
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            break Some(status);
+        }
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            kill_tree(&mut child);
+            timed_out = true;
+            break None;
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+
+    let grace = timed_out.then_some(KILL_GRACE);
+    Ok(CommandOutput {
+        stdout: stdout.finish(grace),
+        stderr: stderr.finish(grace),
+        exit_code: status.and_then(|status| status.code()),
+        timed_out,
+    })
+}
@@ -0,0 +1,244 @@
+//! Per-host cache of gathered facts.
+//!
+//! Facts are stored per host and getter name (e.g. `get_facts`,
+//! `get_interfaces`) and reused until they are older than the cache TTL, so
+//! reporting and compliance runs don't poll every device again. A cache
+//! directory keeps facts across processes.
+
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedFact {
+    /// Seconds since the Unix epoch.
+    gathered_at: u64,
+    value: Value,
+}
+
+impl CachedFact {
+    fn is_fresh(&self, ttl: Duration) -> bool {
+        now().saturating_sub(self.gathered_at) < ttl.as_secs()
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Keeps file names inside the cache directory whatever the host or getter
+/// name contains. Bytes other than lower-case letters, digits, `-` and `_`
+/// are percent-encoded, so distinct names never share a file, not even on
+/// case-insensitive file systems.
+fn file_name(name: &str) -> String {
+    let mut encoded = String::with_capacity(name.len());
+    for byte in name.bytes() {
+        match byte {
+            b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Memory cache of facts with an optional directory behind it.
+///
+/// # Examples
+///
+/// ```
+/// # use genja_core::facts::FactCache;
+/// # use serde_json::json;
+/// # use std::time::Duration;
+/// let cache = FactCache::new(Duration::from_secs(3600));
+/// let mut polls = 0;
+/// for _ in 0..2 {
+///     let facts = cache
+///         .get_or_gather("r1", "get_facts", || {
+///             polls += 1;
+///             Ok::<_, String>(json!({ "os_version": "4.32" }))
+///         })
+///         .unwrap();
+///     assert_eq!(facts["os_version"], "4.32");
+/// }
+/// assert_eq!(polls, 1);
+/// ```
+#[derive(Debug)]
+pub struct FactCache {
+    ttl: Duration,
+    dir: Option<PathBuf>,
+    refresh: bool,
+    entries: DashMap<(String, String), CachedFact>,
+}
+
+impl FactCache {
+    /// Facts older than `ttl` are gathered again. TTLs are counted in whole
+    /// seconds.
+    pub fn new(ttl: Duration) -> Self {
+        FactCache {
+            ttl,
+            dir: None,
+            refresh: false,
+            entries: DashMap::new(),
+        }
+    }
+
+    /// Also stores facts as `<dir>/<host>/<getter>.json`, with the names
+    /// percent-encoded.
+    pub fn with_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = Some(dir.into());
+        self
+    }
+
+    /// Ignores cached facts when reading while still storing new ones, the
+    /// equivalent of `--refresh-facts`.
+    pub fn refresh(mut self, refresh: bool) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    fn path(&self, host: &str, getter: &str) -> Option<PathBuf> {
+        let dir = self.dir.as_ref()?;
+        Some(
+            dir.join(file_name(host))
+                .join(format!("{}.json", file_name(getter))),
+        )
+    }
+
+    fn read_disk(path: &Path) -> Option<CachedFact> {
+        let contents = fs::read(path).ok()?;
+        match serde_json::from_slice(&contents) {
+            Ok(fact) => Some(fact),
+            Err(error) => {
+                log::warn!("ignoring corrupt fact cache {}: {error}", path.display());
+                None
+            }
+        }
+    }
+
+    /// Returns the cached facts if they are younger than the TTL.
+    pub fn get(&self, host: &str, getter: &str) -> Option<Value> {
+        if self.refresh {
+            return None;
+        }
+        let key = (host.to_string(), getter.to_string());
+        if let Some(fact) = self.entries.get(&key) {
+            if fact.is_fresh(self.ttl) {
+                return Some(fact.value.clone());
+            }
+        }
+        let fact = Self::read_disk(&self.path(host, getter)?)?;
+        if !fact.is_fresh(self.ttl) {
+            return None;
+        }
+        let value = fact.value.clone();
+        self.entries.insert(key, fact);
+        Some(value)
+    }
+
+    /// Stores facts gathered now. Failing to write the cache directory is
+    /// logged and otherwise ignored.
+    pub fn insert(&self, host: &str, getter: &str, value: Value) {
+        let fact = CachedFact {
+            gathered_at: now(),
+            value,
+        };
+        if let Some(path) = self.path(host, getter) {
+            let written = path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|()| fs::write(&path, serde_json::to_vec(&fact)?));
+            if let Err(error) = written {
+                log::warn!("failed to write fact cache {}: {error}", path.display());
+            }
+        }
+        self.entries
+            .insert((host.to_string(), getter.to_string()), fact);
+    }
+
+    /// Returns the cached facts, or runs `gather` and caches its result.
+    pub fn get_or_gather<E>(
+        &self,
+        host: &str,
+        getter: &str,
+        gather: impl FnOnce() -> Result<Value, E>,
+    ) -> Result<Value, E> {
+        if let Some(value) = self.get(host, getter) {
+            return Ok(value);
+        }
+        let value = gather()?;
+        self.insert(host, getter, value.clone());
+        Ok(value)
+    }
+
+    /// Drops all cached facts of `host`, in memory and on disk.
+    pub fn invalidate(&self, host: &str) {
+        self.entries.retain(|(cached, _), _| cached != host);
+        if let Some(dir) = &self.dir {
+            let _ = fs::remove_dir_all(dir.join(file_name(host)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+    use serde_json::json;
+
+    #[test]
+    fn test_expired_facts_are_gathered_again() {
+        let cache = FactCache::new(Duration::ZERO);
+        cache.insert("r1", "get_facts", json!({ "uptime": 1 }));
+        assert_eq!(cache.get("r1", "get_facts"), None);
+    }
+
+    #[test]
+    fn test_refresh_bypasses_reads() {
+        let cache = FactCache::new(Duration::from_secs(60)).refresh(true);
+        let mut polls = 0;
+        for _ in 0..2 {
+            cache
+                .get_or_gather("r1", "get_facts", || {
+                    polls += 1;
+                    Ok::<_, ()>(json!({}))
+                })
+                .unwrap();
+        }
+        assert_eq!(polls, 2);
+    }
+
+    #[test]
+    fn test_disk_cache_survives_new_instances() {
+        let dir = TempDir::new("facts");
+        let cache = FactCache::new(Duration::from_secs(60)).with_dir(dir.path());
+        cache.insert(
+            "r1/../x",
+            "get_interfaces",
+            json!({ "Et1": { "up": true } }),
+        );
+        cache.insert("r1____x", "get_interfaces", json!({}));
+        assert!(dir
+            .join("r1%2F%2E%2E%2Fx")
+            .join("get_interfaces.json")
+            .exists());
+
+        let reloaded = FactCache::new(Duration::from_secs(60)).with_dir(dir.path());
+        assert_eq!(
+            reloaded.get("r1/../x", "get_interfaces"),
+            Some(json!({ "Et1": { "up": true } }))
+        );
+
+        reloaded.invalidate("r1/../x");
+        assert_eq!(reloaded.get("r1/../x", "get_interfaces"), None);
+        // Names that only differ in escaped characters keep their own files.
+        let reloaded = FactCache::new(Duration::from_secs(60)).with_dir(dir.path());
+        assert_eq!(reloaded.get("r1____x", "get_interfaces"), Some(json!({})));
+        // Other instances keep their memory copy until it expires.
+        assert!(cache.get("r1/../x", "get_interfaces").is_some());
+    }
+}
@@ -1,13 +1,81 @@
+use crate::types::{numeric_suffix, CowStr};
 use crate::CustomTreeMap;
 use dashmap::DashMap;
-use genja_core_derive::{DerefMacro, DerefMutMacro};
+use genja_core_derive::{DerefMacro, DerefMutMacro, IndexMacro};
+#[cfg(feature = "schema")]
 use schemars::{schema_for, JsonSchema};
-use serde::de::{Error, SeqAccess, Unexpected, Visitor};
+use serde::de::{DeserializeOwned, Error, SeqAccess, Unexpected, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
 use std::fmt;
+use std::ops::RangeBounds;
 use std::sync::{Arc, Mutex};
 
+mod annotations;
+mod anonymize;
+mod ansible;
+mod capabilities;
+mod clusters;
+mod containerlab;
+mod contexts;
+mod credentials;
+mod csv;
+mod diagram;
+mod duplicates;
+mod extends;
+mod frozen;
+mod incremental;
+mod migrations;
+mod pipeline;
+mod plugins;
+mod proxy;
+#[cfg(feature = "query")]
+mod query;
+mod relaxed;
+mod resolve;
+mod scheduling;
+mod simple;
+mod tls;
+mod validation;
+
+pub use annotations::Annotation;
+pub use anonymize::{is_secret_key, AnonymizeOptions};
+pub use capabilities::{
+    BatchOutcome, Capability, CapabilityError, ConfigSession, Exec, FileTransfer, Subscribe,
+    SubscriptionCallback, SupportsConfigSession, SupportsExec, SupportsFileTransfer,
+    SupportsSubscribe,
+};
+pub use clusters::{Cluster, ClusterMembership, ClusterRole};
+pub use containerlab::ContainerlabOptions;
+pub use contexts::DeviceContext;
+use credentials::CredentialState;
+pub use credentials::{Credential, CredentialError};
+pub use csv::CsvOptions;
+pub use diagram::{Diagram, DiagramOptions};
+pub use duplicates::{Conflict, ConflictKind, ConflictReport, DuplicatePolicies, DuplicatePolicy};
+pub use extends::compose_file;
+pub use frozen::{FrozenInventory, SharedInventory};
+pub use incremental::{IncrementalLoader, LoadProgress};
+pub use migrations::{
+    migrate, AppliedMigration, MigrationOptions, MigrationReport, SCHEMA_VERSION,
+};
+pub use pipeline::{LoadPipeline, LoadReport, LoadStage, StageData, StageTiming};
+pub use plugins::{
+    load_inventory_plugin, plugin_options, register_inventory_plugin, InventoryPlugin,
+    InventoryPluginRegistry,
+};
+pub use proxy::ProxyConfig;
+#[cfg(feature = "query")]
+pub(crate) use query::{compile_query, query_matches};
+pub use relaxed::UnknownFieldWarning;
+pub use resolve::{GroupResolution, ParamProvenance, ParamSource, ResolvedHost};
+pub use scheduling::{GroupLimiter, GroupPermit, SchedulingHints, TimeWindow};
+pub use simple::SimpleInventory;
+pub use tls::TlsConfig;
+pub use validation::{Severity, ValidationIssue, ValidationProfile};
+
 pub trait BaseMethods {
+    #[cfg(feature = "schema")]
     fn schema() -> String
     where
         Self: Sized,
@@ -48,8 +116,161 @@ pub trait BaseBuilderHost {
     /// Updates the defaults and returns the updated builder.
     fn defaults(self, defaults: &Arc<Defaults>) -> Self;
 
+    /// Adds a single group to the groups and returns the updated builder.
+    ///
+    /// Groups already present are not added twice. The default
+    /// implementation cannot see the current groups and sets them to
+    /// `group` alone; builders that keep their groups should override it.
+    fn group(self, group: &str) -> Self
+    where
+        Self: Sized,
+    {
+        self.groups(ParentGroups(vec![group.to_string()]))
+    }
+
     /// Builds the struct from the updated builder and returns final struct object.
     fn build(self) -> Self::Output;
+
+    /// Validates the builder and returns the final struct object, or an
+    /// `InventoryError` describing the first invalid field.
+    ///
+    /// The default implementation does no validation and calls
+    /// [`BaseBuilderHost::build`].
+    fn try_build(self) -> Result<Self::Output, InventoryError>
+    where
+        Self: Sized,
+    {
+        Ok(self.build())
+    }
+
+    /// Updates the hostname if `Some`, otherwise leaves it unchanged.
+    fn maybe_hostname(self, hostname: Option<&str>) -> Self
+    where
+        Self: Sized,
+    {
+        match hostname {
+            Some(hostname) => self.hostname(hostname),
+            None => self,
+        }
+    }
+
+    /// Updates the port if `Some`, otherwise leaves it unchanged.
+    fn maybe_port(self, port: Option<u16>) -> Self
+    where
+        Self: Sized,
+    {
+        match port {
+            Some(port) => self.port(port),
+            None => self,
+        }
+    }
+
+    /// Updates the username if `Some`, otherwise leaves it unchanged.
+    fn maybe_username(self, username: Option<&str>) -> Self
+    where
+        Self: Sized,
+    {
+        match username {
+            Some(username) => self.username(username),
+            None => self,
+        }
+    }
+
+    /// Updates the password if `Some`, otherwise leaves it unchanged.
+    fn maybe_password(self, password: Option<&str>) -> Self
+    where
+        Self: Sized,
+    {
+        match password {
+            Some(password) => self.password(password),
+            None => self,
+        }
+    }
+
+    /// Updates the platform if `Some`, otherwise leaves it unchanged.
+    fn maybe_platform(self, platform: Option<&str>) -> Self
+    where
+        Self: Sized,
+    {
+        match platform {
+            Some(platform) => self.platform(platform),
+            None => self,
+        }
+    }
+
+    /// Updates the data from a raw json value, e.g. `json!({"role": "core"})`.
+    fn data_json(self, data: serde_json::Value) -> Self
+    where
+        Self: Sized,
+    {
+        self.data(Data::new(data))
+    }
+}
+
+/// Errors raised while building or loading an inventory.
+#[derive(Debug)]
+pub enum InventoryError {
+    /// A host or group field failed validation.
+    Validation { name: String, message: String },
+    /// A query expression failed to compile or evaluate.
+    Query { expression: String, message: String },
+    /// A host collided with an existing one under `DuplicatePolicy::Error`.
+    Duplicate(Conflict),
+    /// A document could not be upgraded to the current schema version.
+    Migration { location: String, message: String },
+    /// A stage of [`LoadPipeline`] or one of its hooks failed.
+    Load { stage: LoadStage, message: String },
+    /// An inventory plugin is unknown or failed to load.
+    Plugin { name: String, message: String },
+}
+
+impl fmt::Display for InventoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InventoryError::Validation { name, message } => {
+                write!(f, "invalid inventory entry `{name}`: {message}")
+            }
+            InventoryError::Query {
+                expression,
+                message,
+            } => write!(f, "invalid query `{expression}`: {message}"),
+            InventoryError::Duplicate(conflict) => write!(f, "{conflict}"),
+            InventoryError::Migration { location, message } => {
+                write!(f, "cannot migrate `{location}`: {message}")
+            }
+            InventoryError::Load { stage, message } => {
+                write!(f, "inventory load failed at the {stage} stage: {message}")
+            }
+            InventoryError::Plugin { name, message } => {
+                write!(f, "inventory plugin `{name}`: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InventoryError {}
+
+/// Shared validation for the fields common to `HostBuilder` and `GroupBuilder`.
+fn validate_common(
+    name: &str,
+    hostname: Option<&str>,
+    port: Option<u16>,
+    groups: Option<&ParentGroups>,
+) -> Result<(), InventoryError> {
+    let invalid = |message: &str| InventoryError::Validation {
+        name: name.to_string(),
+        message: message.to_string(),
+    };
+    if hostname.is_some_and(|hostname| hostname.trim().is_empty()) {
+        return Err(invalid("hostname must not be empty"));
+    }
+    if port == Some(0) {
+        return Err(invalid("port must be between 1 and 65535"));
+    }
+    if groups.is_some_and(|groups| groups.iter().any(|group| group.trim().is_empty())) {
+        return Err(invalid("group names must not be empty"));
+    }
+    Ok(())
 }
 
 // Required for the DerefMacro derive to satisfy the DerefTarget trait.
@@ -57,7 +278,8 @@ pub trait DerefTarget {
     type Target;
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct ConnectionOptions {
     pub hostname: Option<String>,
     pub port: Option<u16>,
@@ -65,6 +287,10 @@ pub struct ConnectionOptions {
     pub password: Option<String>,
     pub platform: Option<String>,
     pub extras: Option<Extras>,
+    /// Fallback credentials tried after `username`/`password` are rejected.
+    pub credentials: Option<Vec<Credential>>,
+    pub tls: Option<TlsConfig>,
+    pub proxy: Option<ProxyConfig>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -75,6 +301,68 @@ pub struct ResolvedConnectionParams {
     pub password: Option<String>,
     pub platform: Option<String>,
     pub extras: Option<Extras>,
+    /// Fallback credentials, see [`ResolvedConnectionParams::credential_candidates`].
+    pub credentials: Vec<Credential>,
+    pub tls: Option<TlsConfig>,
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl ResolvedConnectionParams {
+    /// Deserializes the resolved `extras` into a plugin specific options type.
+    pub fn extras_as<T: DeserializeOwned>(&self) -> Result<Option<T>, serde_json::Error> {
+        self.extras
+            .as_ref()
+            .map(|extras| T::deserialize(&extras.0))
+            .transpose()
+    }
+}
+
+/// Displays `hostname:port (platform) user=username`, omitting unset fields.
+/// The password is never displayed.
+impl fmt::Display for ResolvedConnectionParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_address(f, &self.hostname, self.port)?;
+        write!(f, " ({})", self.platform.as_deref().unwrap_or("-"))?;
+        if let Some(username) = &self.username {
+            write!(f, " user={username}")?;
+        }
+        Ok(())
+    }
+}
+
+fn write_address(f: &mut fmt::Formatter<'_>, hostname: &str, port: Option<u16>) -> fmt::Result {
+    match port {
+        Some(port) => write!(f, "{hostname}:{port}"),
+        None => write!(f, "{hostname}"),
+    }
+}
+
+/// Writes the fields shared by the `Host` and `Group` one-line formats.
+///
+/// The alternate flag (`{:#}`) selects the wide format, which also lists the
+/// number of data keys and the configured connection option plugins.
+fn write_summary(
+    f: &mut fmt::Formatter<'_>,
+    platform: Option<&str>,
+    groups: Option<&ParentGroups>,
+    data: Option<&Data>,
+    connection_options: Option<&CustomTreeMap<ConnectionOptions>>,
+) -> fmt::Result {
+    write!(f, " ({})", platform.unwrap_or("-"))?;
+    if let Some(groups) = groups.filter(|groups| !groups.is_empty()) {
+        write!(f, " groups={}", groups.join(","))?;
+    }
+    if f.alternate() {
+        let data_keys = data
+            .and_then(|data| data.as_object())
+            .map_or(0, |data| data.len());
+        write!(f, " data_keys={data_keys}")?;
+        if let Some(options) = connection_options.filter(|options| !options.is_empty()) {
+            let plugins: Vec<&str> = options.keys_str().collect();
+            write!(f, " connection_options={}", plugins.join(","))?;
+        }
+    }
+    Ok(())
 }
 
 impl Default for ConnectionOptions {
@@ -92,8 +380,58 @@ impl ConnectionOptions {
             password: None,
             platform: None,
             extras: None,
+            credentials: None,
+            tls: None,
+            proxy: None,
         }
     }
+
+    /// Deserializes the `extras` into a plugin specific options type.
+    ///
+    /// Returns `Ok(None)` when no extras are set.
+    pub fn extras_as<T: DeserializeOwned>(&self) -> Result<Option<T>, serde_json::Error> {
+        self.extras
+            .as_ref()
+            .map(|extras| T::deserialize(&extras.0))
+            .transpose()
+    }
+}
+
+/// Typed connection options for a specific connection plugin.
+///
+/// Each plugin declares the key it is registered under in the host's
+/// `connection_options` map and the shape of the `extras` it understands.
+/// Plugins without a typed options struct keep using the untyped
+/// `ConnectionOptions::extras`.
+///
+/// # Examples
+///
+/// ```
+/// # use genja_core::inventory::{BaseBuilderHost, ConnectionOptions, Host, PluginOptions};
+/// # use serde::Deserialize;
+/// #[derive(Deserialize)]
+/// struct NetconfOptions {
+///     hostkey_verify: bool,
+/// }
+///
+/// impl PluginOptions for NetconfOptions {
+///     const PLUGIN: &'static str = "netconf";
+/// }
+///
+/// let options: ConnectionOptions = serde_json::from_value(serde_json::json!({
+///     "port": 830,
+///     "extras": { "hostkey_verify": false }
+/// })).unwrap();
+/// let host = Host::builder("r1")
+///     .connection_options("netconf".to_string(), options)
+///     .build();
+///
+/// let netconf = host.plugin_options::<NetconfOptions>().unwrap().unwrap();
+/// assert!(!netconf.hostkey_verify);
+/// ```
+pub trait PluginOptions: DeserializeOwned {
+    /// The connection plugin name used as the `connection_options` key.
+    const PLUGIN: &'static str;
 }
 
 impl DerefTarget for Extras {
@@ -101,9 +439,8 @@ impl DerefTarget for Extras {
 }
 
 /// The DataExtra struct is a wrapper for serde_json::Value, any json data is accepted.
-#[derive(
-    Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema, DerefMacro, DerefMutMacro,
-)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, DerefMacro, DerefMutMacro)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct Extras(serde_json::Value);
 
 impl DerefTarget for ParentGroups {
@@ -117,7 +454,8 @@ impl DerefTarget for ParentGroups {
 ///
 /// The ParentGroups struct implements Deref and DerefMut for easy
 /// access to the underlying vector.
-#[derive(Debug, Clone, Serialize, PartialEq, JsonSchema, DerefMacro, DerefMutMacro)]
+#[derive(Debug, Clone, Serialize, PartialEq, DerefMacro, DerefMutMacro, IndexMacro)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct ParentGroups(Vec<String>);
 
 impl Default for ParentGroups {
@@ -169,14 +507,17 @@ impl<'de> Visitor<'de> for ParentGroupsVisitor {
     ///
     /// The vector implementation ensures that duplicate strings are not added to the
     /// and preserves the order of the first occurrence of each string.
+    ///
+    /// Elements are read as `CowStr`, so duplicates are discarded without
+    /// allocating when the deserializer can lend out borrowed strings.
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
-        let mut groups = Vec::new();
-        while let Some(value) = seq.next_element()? {
-            if !groups.contains(&value) {
-                groups.push(value);
+        let mut groups: Vec<String> = Vec::new();
+        while let Some(CowStr(value)) = seq.next_element()? {
+            if !groups.iter().any(|group| *group == value) {
+                groups.push(value.into_owned());
             }
         }
 
@@ -188,33 +529,66 @@ impl DerefTarget for Defaults {
     type Target = serde_json::Value;
 }
 
-#[derive(
-    Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, DerefMacro, DerefMutMacro,
-)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, DerefMacro, DerefMutMacro)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct Defaults(serde_json::Value);
 
+impl Defaults {
+    pub fn new(defaults: serde_json::Value) -> Self {
+        Defaults(defaults)
+    }
+}
+
 impl DerefTarget for Data {
     type Target = serde_json::Value;
 }
 
 /// The Data struct is a wrapper for serde_json::Value, any json data is accepted.
-#[derive(
-    Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, DerefMacro, DerefMutMacro,
-)]
-pub struct Data(serde_json::Value);
+///
+/// The value is stored behind an `Arc` so identical data blobs can be shared
+/// between hosts (see `Hosts::share_data`). Cloning a `Data` is cheap and
+/// mutable access through `DerefMut` is copy-on-write: the value is only
+/// copied when it is shared with another `Data`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, DerefMacro)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct Data(Arc<serde_json::Value>);
+
+impl std::ops::DerefMut for Data {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        Arc::make_mut(&mut self.0)
+    }
+}
 
 impl Data {
     pub fn new(data: serde_json::Value) -> Self {
+        Data(Arc::new(data))
+    }
+
+    /// Wraps an already shared value without copying it.
+    pub fn from_arc(data: Arc<serde_json::Value>) -> Self {
         Data(data)
     }
+
+    /// Returns the shared value.
+    pub fn as_arc(&self) -> &Arc<serde_json::Value> {
+        &self.0
+    }
+
+    /// Returns `true` if both `Data` point to the same shared value.
+    pub fn ptr_eq(&self, other: &Data) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct Host {
     pub name: String,
+    #[serde(alias = "ip")]
     pub hostname: Option<String>,
     pub port: Option<u16>,
+    #[serde(alias = "user")]
     pub username: Option<String>,
     pub password: Option<String>,
     pub platform: Option<String>,
@@ -223,7 +597,7 @@ pub struct Host {
     pub connection_options: Option<CustomTreeMap<ConnectionOptions>>,
     pub defaults: Option<Arc<Defaults>>,
     #[serde(skip)]
-    #[schemars(skip)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
     pub resolved_connection_params: CustomTreeMap<ResolvedConnectionParams>,
 }
 
@@ -247,6 +621,30 @@ impl Host {
         HostBuilder::new(name)
     }
 
+    /// Returns `true` if the number the host name ends with is in `range`,
+    /// e.g. `genja.filter(|host| host.name_index_in(10..=20))` for devices
+    /// whose names encode a rack or slot number. See
+    /// [`NatString::numeric_suffix`](crate::NatString::numeric_suffix).
+    pub fn name_index_in(&self, range: impl RangeBounds<u64>) -> bool {
+        numeric_suffix(&self.name).is_some_and(|index| range.contains(&index))
+    }
+
+    /// Returns the typed options for plugin `T` from the host's own
+    /// `connection_options`, or `Ok(None)` if the plugin has no extras set.
+    pub fn plugin_options<T: PluginOptions>(&self) -> Result<Option<T>, serde_json::Error> {
+        match self
+            .connection_options
+            .as_ref()
+            .and_then(|options| options.get(T::PLUGIN))
+        {
+            Some(options) => options.extras_as(),
+            None => Ok(None),
+        }
+    }
+
+    /// Resolves and caches the parameters for `connection_type` from this
+    /// host's own fields only. Groups and defaults are not consulted, see
+    /// [`Inventory::resolve_connection_params`] for the full resolution.
     pub fn resolve_connection_params(
         &mut self,
         connection_type: &str,
@@ -263,6 +661,9 @@ impl Host {
                 password: self.password.clone(),
                 platform: self.platform.clone(),
                 extras: None,
+                credentials: Vec::new(),
+                tls: None,
+                proxy: None,
             };
 
             if let Some(options_map) = &self.connection_options {
@@ -285,6 +686,15 @@ impl Host {
                     if options.extras.is_some() {
                         resolved.extras = options.extras.clone();
                     }
+                    if let Some(credentials) = &options.credentials {
+                        resolved.credentials = credentials.clone();
+                    }
+                    if options.tls.is_some() {
+                        resolved.tls = options.tls.clone();
+                    }
+                    if options.proxy.is_some() {
+                        resolved.proxy = options.proxy.clone();
+                    }
                 }
             }
 
@@ -300,6 +710,24 @@ impl Host {
 
 impl BaseMethods for Host {}
 
+/// Displays a compact one-line summary: `name hostname:port (platform) groups=a,b`.
+///
+/// The hostname falls back to the host name when unset. Use `{:#}` for the
+/// wide format including data key counts and connection option plugins.
+impl fmt::Display for Host {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ", self.name)?;
+        write_address(f, self.hostname.as_deref().unwrap_or(&self.name), self.port)?;
+        write_summary(
+            f,
+            self.platform.as_deref(),
+            self.groups.as_ref(),
+            self.data.as_ref(),
+            self.connection_options.as_ref(),
+        )
+    }
+}
+
 pub struct HostBuilder {
     name: String,
     hostname: Option<String>,
@@ -384,6 +812,30 @@ impl BaseBuilderHost for HostBuilder {
         self
     }
 
+    fn group(mut self, group: &str) -> Self {
+        let groups = self.groups.get_or_insert_with(ParentGroups::new);
+        if !groups.iter().any(|existing| existing == group) {
+            groups.push(group.to_string());
+        }
+        self
+    }
+
+    fn try_build(self) -> Result<Host, InventoryError> {
+        if self.name.trim().is_empty() {
+            return Err(InventoryError::Validation {
+                name: self.name,
+                message: "host name must not be empty".to_string(),
+            });
+        }
+        validate_common(
+            &self.name,
+            self.hostname.as_deref(),
+            self.port,
+            self.groups.as_ref(),
+        )?;
+        Ok(self.build())
+    }
+
     fn build(self) -> Host {
         Host {
             name: self.name,
@@ -401,7 +853,8 @@ impl BaseBuilderHost for HostBuilder {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct Group {
     pub hostname: Option<String>,
     pub port: Option<u16>,
@@ -420,6 +873,22 @@ impl Default for Group {
     }
 }
 
+/// Displays a compact one-line summary: `hostname:port (platform) groups=a,b`.
+///
+/// Use `{:#}` for the wide format, see the `Host` implementation.
+impl fmt::Display for Group {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_address(f, self.hostname.as_deref().unwrap_or("-"), self.port)?;
+        write_summary(
+            f,
+            self.platform.as_deref(),
+            self.groups.as_ref(),
+            self.data.as_ref(),
+            self.connection_options.as_ref(),
+        )
+    }
+}
+
 impl Group {
     pub fn new() -> Group {
         Group {
@@ -498,6 +967,23 @@ impl BaseBuilderHost for GroupBuilder {
         self.defaults = Some(Arc::clone(defaults));
         self
     }
+    fn group(mut self, group: &str) -> Self {
+        let groups = self.groups.get_or_insert_with(ParentGroups::new);
+        if !groups.iter().any(|existing| existing == group) {
+            groups.push(group.to_string());
+        }
+        self
+    }
+    fn try_build(self) -> Result<Group, InventoryError> {
+        let name = self.hostname.clone().unwrap_or_default();
+        validate_common(
+            &name,
+            self.hostname.as_deref(),
+            self.port,
+            self.groups.as_ref(),
+        )?;
+        Ok(self.build())
+    }
     fn build(self) -> Group {
         Group {
             hostname: self.hostname,
@@ -535,8 +1021,10 @@ impl DerefTarget for Hosts {
     type Target = CustomTreeMap<Host>;
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, DerefMacro, DerefMutMacro)]
+#[derive(Debug, Clone, Serialize, Deserialize, DerefMacro, DerefMutMacro, IndexMacro)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
+#[index(map)]
 pub struct Hosts(HostsTarget);
 
 impl Default for Hosts {
@@ -550,15 +1038,47 @@ impl Hosts {
         Hosts(CustomTreeMap::new())
     }
 
+    /// Adds `host`, replacing any host with the same name. Use
+    /// [`Hosts::insert_with_policy`] to detect or merge duplicates instead.
     pub fn add_host(&mut self, host: Host) {
         let name = host.name.clone();
         self.insert(name, host);
     }
+
+    /// Makes hosts with identical `Data` share a single allocation.
+    ///
+    /// Inventories loaded from files hold one copy of the data per host even
+    /// when many hosts carry the same site-wide values. Returns the number of
+    /// hosts whose data now points at a value shared with an earlier host.
+    pub fn share_data(&mut self) -> usize {
+        let mut seen: HashMap<String, Data> = HashMap::new();
+        let mut shared = 0;
+        for host in self.values_mut() {
+            let Some(data) = host.data.as_mut() else {
+                continue;
+            };
+            // serde_json maps are sorted, so equal values serialize identically.
+            let key = data.to_string();
+            match seen.get(&key) {
+                Some(existing) => {
+                    if !existing.ptr_eq(data) {
+                        *data = existing.clone();
+                    }
+                    shared += 1;
+                }
+                None => {
+                    seen.insert(key, data.clone());
+                }
+            }
+        }
+        shared
+    }
 }
 
 impl BaseMethods for Hosts {}
 
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, DerefMacro, DerefMutMacro)]
+#[derive(Debug, Clone, Serialize, Deserialize, DerefMacro, DerefMutMacro, IndexMacro)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct Groups(CustomTreeMap<Group>);
 
 impl DerefTarget for Groups {
@@ -596,17 +1116,25 @@ impl fmt::Debug for TransformFunction {
 }
 
 /// The TransformFunctionOptions struct is a wrapper for serde_json::Value, any json data is accepted.
-#[derive(
-    Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, DerefMacro, DerefMutMacro,
-)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, DerefMacro, DerefMutMacro)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct TransformFunctionOptions(serde_json::Value);
 
 impl DerefTarget for TransformFunctionOptions {
     type Target = serde_json::Value;
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct Inventory {
+    /// Schema version of the document, see [`migrate`]. Defaults to
+    /// [`SCHEMA_VERSION`]; newer versions are rejected. Older documents
+    /// should go through [`migrate`] or [`LoadPipeline`] first.
+    #[serde(
+        default = "migrations::current_schema_version",
+        deserialize_with = "migrations::deserialize_schema_version"
+    )]
+    pub schema_version: u32,
     pub hosts: Hosts,
     pub groups: Option<Groups>,
     pub defaults: Option<Defaults>,
@@ -614,9 +1142,16 @@ pub struct Inventory {
     #[serde(skip)]
     pub transform_function: Option<TransformFunction>,
     pub transform_function_options: Option<TransformFunctionOptions>,
+    /// Order used to resolve a host's groups, see [`Inventory::resolve_host`].
+    #[serde(default)]
+    pub group_resolution: GroupResolution,
     #[serde(skip)]
-    #[schemars(skip)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
     pub connections: Arc<ConnectionManager>,
+    /// Issues found by the last [`Inventory::validate_with`].
+    #[serde(skip)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub issues: Vec<ValidationIssue>,
 }
 
 pub trait Connection
@@ -628,6 +1163,26 @@ where
     fn open(&mut self, params: &ResolvedConnectionParams) -> Result<(), String>;
 
     fn close(&mut self) -> ConnectionKey;
+
+    /// Returns `Some(self)` if the connection implements [`SupportsExec`].
+    fn as_exec(&mut self) -> Option<&mut (dyn SupportsExec + 'static)> {
+        None
+    }
+
+    /// Returns `Some(self)` if the connection implements [`SupportsFileTransfer`].
+    fn as_file_transfer(&mut self) -> Option<&mut (dyn SupportsFileTransfer + 'static)> {
+        None
+    }
+
+    /// Returns `Some(self)` if the connection implements [`SupportsConfigSession`].
+    fn as_config_session(&mut self) -> Option<&mut (dyn SupportsConfigSession + 'static)> {
+        None
+    }
+
+    /// Returns `Some(self)` if the connection implements [`SupportsSubscribe`].
+    fn as_subscribe(&mut self) -> Option<&mut (dyn SupportsSubscribe + 'static)> {
+        None
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
@@ -649,6 +1204,7 @@ impl ConnectionKey {
 #[derive(Debug, Default)]
 pub struct ConnectionManager {
     connections_map: DashMap<ConnectionKey, Arc<Mutex<dyn Connection>>>,
+    credential_state: DashMap<ConnectionKey, CredentialState>,
 }
 
 impl ConnectionManager {
@@ -664,6 +1220,12 @@ impl ConnectionManager {
 
     // TODO: Include the logic to use the pluginManager to load and create connections
     // with the use on the config held in the Nornir Struct.
+    /// Returns the connection stored under `key`, creating it with `ctor` if
+    /// it does not exist yet.
+    ///
+    /// `ctor` runs while the map entry is locked, so concurrent callers for the
+    /// same key never construct more than one connection and always receive the
+    /// stored instance. `ctor` must not access this `ConnectionManager`.
     pub fn get_or_create<F, C>(&self, key: ConnectionKey, ctor: F) -> Arc<Mutex<dyn Connection>>
     where
         F: FnOnce() -> C,
@@ -673,11 +1235,20 @@ impl ConnectionManager {
             return connection;
         }
 
-        let connection = Arc::new(Mutex::new(ctor())) as Arc<Mutex<dyn Connection>>;
         self.connections_map
             .entry(key)
-            .or_insert_with(|| connection.clone());
-        connection
+            .or_insert_with(|| Arc::new(Mutex::new(ctor())) as Arc<Mutex<dyn Connection>>)
+            .value()
+            .clone()
+    }
+
+    /// Returns the number of stored connections.
+    pub fn len(&self) -> usize {
+        self.connections_map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.connections_map.is_empty()
     }
 
     /// Close the connection associated with the given key and remove
@@ -710,12 +1281,15 @@ impl BaseMethods for Inventory {}
 impl Inventory {
     pub fn new() -> Inventory {
         Inventory {
+            schema_version: SCHEMA_VERSION,
             hosts: Hosts::new(),
             groups: None,
             defaults: None,
             transform_function: None,
             transform_function_options: None,
+            group_resolution: GroupResolution::default(),
             connections: Arc::new(ConnectionManager::default()),
+            issues: Vec::new(),
         }
     }
 
@@ -743,7 +1317,10 @@ pub struct InventoryBuilder {
     pub defaults: Option<Defaults>,
     pub transform_function: Option<TransformFunction>,
     pub transform_function_options: Option<TransformFunctionOptions>,
+    pub group_resolution: GroupResolution,
     pub connections: Option<Arc<ConnectionManager>>,
+    /// Errors found by `host` and `group`, reported by `try_build`.
+    errors: Vec<InventoryError>,
 }
 
 impl InventoryBuilder {
@@ -754,7 +1331,9 @@ impl InventoryBuilder {
             defaults: None,
             transform_function: None,
             transform_function_options: None,
+            group_resolution: GroupResolution::default(),
             connections: None,
+            errors: Vec::new(),
         }
     }
 
@@ -783,25 +1362,137 @@ impl InventoryBuilder {
         self
     }
 
+    pub fn group_resolution(mut self, strategy: GroupResolution) -> Self {
+        self.group_resolution = strategy;
+        self
+    }
+
     pub fn connections(mut self, connections: ConnectionManager) -> Self {
         self.connections = Some(Arc::new(connections));
         self
     }
 
+    /// Adds the host `name` configured by `configure`.
+    ///
+    /// ```
+    /// # use genja_core::inventory::{BaseBuilderHost, Defaults, Inventory};
+    /// # use serde_json::json;
+    /// let inventory = Inventory::builder()
+    ///     .defaults(Defaults::new(json!({ "username": "admin" })))
+    ///     .group("core", |g| g.platform("eos"))
+    ///     .host("r1", |h| h.hostname("10.0.0.1").group("core"))
+    ///     .try_build()?;
+    /// assert_eq!(inventory.hosts.len(), 1);
+    /// # Ok::<(), genja_core::inventory::InventoryError>(())
+    /// ```
+    pub fn host(mut self, name: &str, configure: impl FnOnce(HostBuilder) -> HostBuilder) -> Self {
+        let hosts = self.hosts.get_or_insert_with(Hosts::new);
+        if hosts.get(name).is_some() {
+            self.errors.push(defined_twice("host", name));
+            return self;
+        }
+        match configure(Host::builder(name)).try_build() {
+            Ok(host) => hosts.add_host(host),
+            Err(error) => self.errors.push(error),
+        }
+        self
+    }
+
+    /// Adds the group `name` configured by `configure`.
+    pub fn group(
+        mut self,
+        name: &str,
+        configure: impl FnOnce(GroupBuilder) -> GroupBuilder,
+    ) -> Self {
+        let groups = self
+            .groups
+            .get_or_insert_with(|| Groups(CustomTreeMap::new()));
+        if groups.get(name).is_some() {
+            self.errors.push(defined_twice("group", name));
+            return self;
+        }
+        // `GroupBuilder::new` stores the name as hostname, which hosts of the
+        // group would inherit.
+        let builder = GroupBuilder {
+            hostname: None,
+            ..Group::builder(name)
+        };
+        match configure(builder).try_build() {
+            Ok(group) => {
+                groups.insert(name, group);
+            }
+            Err(InventoryError::Validation { message, .. }) => {
+                self.errors.push(InventoryError::Validation {
+                    name: name.to_string(),
+                    message,
+                })
+            }
+            Err(error) => self.errors.push(error),
+        }
+        self
+    }
+
+    /// Builds the inventory, returning the first error found by
+    /// [`InventoryBuilder::host`] or [`InventoryBuilder::group`], or a
+    /// reference to a group that is not defined.
+    pub fn try_build(mut self) -> Result<Inventory, InventoryError> {
+        if !self.errors.is_empty() {
+            return Err(self.errors.remove(0));
+        }
+        let groups = self.groups.as_ref();
+        let is_defined = |group: &str| groups.is_some_and(|groups| groups.get(group).is_some());
+        let hosts = self
+            .hosts
+            .iter()
+            .flat_map(|hosts| hosts.values())
+            .map(|host| (host.name.as_str(), host.groups.as_ref()));
+        let groups = groups
+            .iter()
+            .flat_map(|groups| groups.iter())
+            .map(|(name, group)| (name.as_str(), group.groups.as_ref()));
+        for (name, parents) in hosts.chain(groups) {
+            let unknown = parents
+                .into_iter()
+                .flat_map(|parents| parents.iter())
+                .find(|group| !is_defined(group));
+            if let Some(group) = unknown {
+                return Err(InventoryError::Validation {
+                    name: name.to_string(),
+                    message: format!("group `{group}` is not defined"),
+                });
+            }
+        }
+        Ok(self.build())
+    }
+
+    /// Builds the inventory without validation. Errors found by
+    /// [`InventoryBuilder::host`] and [`InventoryBuilder::group`] are
+    /// ignored and the offending entries left out; of a host or group
+    /// defined more than once, the first definition is kept.
     pub fn build(self) -> Inventory {
         Inventory {
+            schema_version: SCHEMA_VERSION,
             hosts: self.hosts.unwrap_or_default(),
             groups: self.groups,
             defaults: self.defaults,
             transform_function: self.transform_function,
             transform_function_options: self.transform_function_options,
+            group_resolution: self.group_resolution,
             connections: self
                 .connections
                 .unwrap_or_else(|| Arc::new(ConnectionManager::default())),
+            issues: Vec::new(),
         }
     }
 }
 
+fn defined_twice(kind: &str, name: &str) -> InventoryError {
+    InventoryError::Validation {
+        name: name.to_string(),
+        message: format!("{kind} is defined more than once"),
+    }
+}
+
 impl Default for InventoryBuilder {
     fn default() -> Self {
         Self::new()
@@ -823,7 +1514,7 @@ mod tests {
                 .username(&format!("user{}", i))
                 .password(&format!("password{}", i))
                 .platform(if i % 2 == 0 { "linux" } else { "windows" })
-                .data(Data(serde_json::json!(vec![format!(
+                .data(Data::new(serde_json::json!(vec![format!(
                     "data for host {}",
                     i
                 )])))
@@ -864,7 +1555,7 @@ mod tests {
                 .username(&format!("user{}", i))
                 .password(&format!("password{}", i))
                 .platform(if i % 2 == 0 { "linux" } else { "windows" })
-                .data(Data(serde_json::json!(vec![format!(
+                .data(Data::new(serde_json::json!(vec![format!(
                     "data for host {}",
                     i
                 )])))
@@ -892,7 +1583,10 @@ mod tests {
         let serialized = serde_json::to_string(&groups).unwrap();
         assert_eq!(serialized, "[\"cisco\",\"Juniper\",\"arista\"]");
         let mut deserialized: ParentGroups = serde_json::from_str(&serialized).unwrap();
-        assert_eq!(deserialized.sort(), ParentGroups(groups).sort());
+        let mut expected = ParentGroups(groups);
+        deserialized.sort();
+        expected.sort();
+        assert_eq!(deserialized, expected);
     }
 
     #[test]
@@ -955,5 +1649,244 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_host_builder_option_setters() {
+        let host = Host::builder("r1")
+            .maybe_hostname(Some("10.0.0.1"))
+            .maybe_port(None)
+            .maybe_platform(Some("eos"))
+            .data_json(serde_json::json!({ "role": "core" }))
+            .group("core")
+            .group("edge")
+            .group("core")
+            .try_build()
+            .unwrap();
+        assert_eq!(host.hostname.as_deref(), Some("10.0.0.1"));
+        assert_eq!(host.port, None);
+        assert_eq!(host.platform.as_deref(), Some("eos"));
+        assert_eq!(
+            host.data,
+            Some(Data::new(serde_json::json!({ "role": "core" })))
+        );
+        assert_eq!(
+            host.groups,
+            Some(ParentGroups(vec!["core".to_string(), "edge".to_string()]))
+        );
+    }
+
+    #[test]
+    fn test_host_builder_try_build_validation() {
+        let err = Host::builder("r1").port(0).try_build().unwrap_err();
+        assert!(matches!(err, InventoryError::Validation { ref name, .. } if name == "r1"));
+        assert!(Host::builder(" ").try_build().is_err());
+        assert!(Host::builder("r1").hostname("").try_build().is_err());
+        assert!(Host::builder("r1").group("").try_build().is_err());
+    }
+
+    #[test]
+    fn test_builder_trait_defaults() {
+        // Implements only the required methods, like builders written
+        // before `group` and `try_build` were added.
+        struct Minimal(HostBuilder);
+        impl BaseBuilderHost for Minimal {
+            type Output = Host;
+            fn hostname(self, hostname: &str) -> Self {
+                Minimal(self.0.hostname(hostname))
+            }
+            fn port(self, port: u16) -> Self {
+                Minimal(self.0.port(port))
+            }
+            fn username(self, username: &str) -> Self {
+                Minimal(self.0.username(username))
+            }
+            fn password(self, password: &str) -> Self {
+                Minimal(self.0.password(password))
+            }
+            fn platform(self, platform: &str) -> Self {
+                Minimal(self.0.platform(platform))
+            }
+            fn groups(self, groups: ParentGroups) -> Self {
+                Minimal(self.0.groups(groups))
+            }
+            fn data(self, data: Data) -> Self {
+                Minimal(self.0.data(data))
+            }
+            fn connection_options(self, name: String, options: ConnectionOptions) -> Self {
+                Minimal(self.0.connection_options(name, options))
+            }
+            fn defaults(self, defaults: &Arc<Defaults>) -> Self {
+                Minimal(self.0.defaults(defaults))
+            }
+            fn build(self) -> Host {
+                self.0.build()
+            }
+        }
+
+        let host = Minimal(Host::builder("r1"))
+            .group("core")
+            .try_build()
+            .unwrap();
+        assert_eq!(host.groups, Some(ParentGroups(vec!["core".to_string()])));
+    }
+
+    #[test]
+    fn test_host_display() {
+        let host = Host::builder("r1")
+            .hostname("10.0.0.1")
+            .port(22)
+            .platform("eos")
+            .group("core")
+            .group("edge")
+            .data_json(serde_json::json!({ "role": "core", "site": "hq" }))
+            .connection_options("ssh".to_string(), ConnectionOptions::new())
+            .build();
+        assert_eq!(host.to_string(), "r1 10.0.0.1:22 (eos) groups=core,edge");
+        assert_eq!(
+            format!("{host:#}"),
+            "r1 10.0.0.1:22 (eos) groups=core,edge data_keys=2 connection_options=ssh"
+        );
+        assert_eq!(Host::new("r2").to_string(), "r2 r2 (-)");
+
+        let mut host = host;
+        let resolved = host.resolve_connection_params("ssh");
+        assert_eq!(resolved.to_string(), "10.0.0.1:22 (eos)");
+    }
+
+    #[test]
+    fn test_group_display() {
+        let group = Group::builder("core").platform("ios").build();
+        assert_eq!(group.to_string(), "core (ios)");
+        assert_eq!(format!("{group:#}"), "core (ios) data_keys=0");
+    }
+
+    #[test]
+    fn test_data_copy_on_write() {
+        let original = Data::new(serde_json::json!({ "site": "hq" }));
+        let mut copy = original.clone();
+        assert!(copy.ptr_eq(&original));
+
+        copy["site"] = serde_json::json!("dc1");
+        assert!(!copy.ptr_eq(&original));
+        assert_eq!(original["site"], "hq");
+        assert_eq!(copy["site"], "dc1");
+    }
+
+    #[test]
+    fn test_hosts_share_data() {
+        let mut hosts: Hosts = serde_json::from_value(serde_json::json!({
+            "r1": { "name": "r1", "data": { "site": "hq", "ntp": ["192.0.2.1"] } },
+            "r2": { "name": "r2", "data": { "ntp": ["192.0.2.1"], "site": "hq" } },
+            "r3": { "name": "r3", "data": { "site": "dc1" } },
+            "r4": { "name": "r4" }
+        }))
+        .unwrap();
+
+        assert_eq!(hosts.share_data(), 1);
+        let data = |name: &str| hosts.get(name).unwrap().data.clone().unwrap();
+        assert!(data("r1").ptr_eq(&data("r2")));
+        assert!(!data("r1").ptr_eq(&data("r3")));
+    }
+
+    #[test]
+    fn test_plugin_options() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct SshOptions {
+            look_for_keys: bool,
+            #[serde(default)]
+            ciphers: Vec<String>,
+        }
+
+        impl PluginOptions for SshOptions {
+            const PLUGIN: &'static str = "ssh";
+        }
+
+        let mut ssh = ConnectionOptions::new();
+        ssh.extras = Some(Extras(serde_json::json!({ "look_for_keys": false })));
+        let mut http = ConnectionOptions::new();
+        http.extras = Some(Extras(serde_json::json!({ "verify": true })));
+
+        let mut host = Host::builder("r1")
+            .connection_options("ssh".to_string(), ssh)
+            .connection_options("http".to_string(), http)
+            .build();
+
+        assert_eq!(
+            host.plugin_options::<SshOptions>().unwrap(),
+            Some(SshOptions {
+                look_for_keys: false,
+                ciphers: Vec::new()
+            })
+        );
+        assert!(host
+            .resolve_connection_params("http")
+            .extras_as::<SshOptions>()
+            .is_err());
+        assert_eq!(
+            Host::new("r2").plugin_options::<SshOptions>().unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_index_hosts_and_groups() {
+        let mut hosts = Hosts::new();
+        hosts.add_host(Host::builder("r1").platform("eos").build());
+        hosts["r1"].port = Some(22);
+        assert_eq!(hosts["r1"].port, Some(22));
+        assert_eq!(hosts["r1"].platform.as_deref(), Some("eos"));
+
+        let groups = ParentGroups(vec!["core".to_string()]);
+        assert_eq!(groups[0], "core");
+    }
+
+    #[test]
+    #[should_panic(expected = "Hosts has no entry `r9`")]
+    fn test_index_missing_host_panics() {
+        let _ = &Hosts::new()["r9"];
+    }
+
+    #[test]
+    fn test_name_index_in() {
+        assert!(Host::new("leaf12").name_index_in(10..=20));
+        assert!(!Host::new("leaf21").name_index_in(10..=20));
+        assert!(!Host::new("spine").name_index_in(..));
+    }
+
+    #[test]
+    fn test_inventory_builder_dsl() {
+        let inventory = Inventory::builder()
+            .group("core", |g| g.platform("eos").port(22))
+            .group("dc1", |g| g.group("core"))
+            .host("r1", |h| h.hostname("10.0.0.1").group("dc1"))
+            .try_build()
+            .unwrap();
+        let host = inventory.hosts.get("r1").unwrap();
+        let params = inventory.resolve_connection_params(host, "ssh");
+        assert_eq!(params.to_string(), "10.0.0.1:22 (eos)");
+
+        let error = |builder: InventoryBuilder| builder.try_build().unwrap_err().to_string();
+        assert_eq!(
+            error(Inventory::builder().host("r1", |h| h.group("edge"))),
+            "invalid inventory entry `r1`: group `edge` is not defined"
+        );
+        assert_eq!(
+            error(Inventory::builder().group("core", |g| g.port(0))),
+            "invalid inventory entry `core`: port must be between 1 and 65535"
+        );
+        assert_eq!(
+            error(
+                Inventory::builder()
+                    .host("r1", |h| h)
+                    .host("r1", |h| h.port(22))
+            ),
+            "invalid inventory entry `r1`: host is defined more than once"
+        );
+        let inventory = Inventory::builder()
+            .host("r1", |h| h.port(22))
+            .host("r1", |h| h.port(830))
+            .build();
+        assert_eq!(inventory.hosts["r1"].port, Some(22));
+    }
+
     // TODO: Create a test to verify the Host defaults deserialization
 }
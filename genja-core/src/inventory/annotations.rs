@@ -0,0 +1,162 @@
+use super::{Data, Host, Inventory, Severity};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Data key holding a host's annotations.
+const ANNOTATIONS_KEY: &str = "annotations";
+
+/// A finding attached to a host, e.g. `needs-reboot` or
+/// `cert-expires: 2025-01-01`.
+///
+/// Annotations are kept in the host data under `annotations`, so they
+/// survive serialization and can be used in filters and queries.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Annotation {
+    pub key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default = "info")]
+    pub severity: Severity,
+}
+
+fn info() -> Severity {
+    Severity::Info
+}
+
+impl Annotation {
+    pub fn new(key: &str, severity: Severity) -> Self {
+        Annotation {
+            key: key.to_string(),
+            value: None,
+            severity,
+        }
+    }
+
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+}
+
+impl Host {
+    /// The host's annotations. Invalid entries are logged and skipped.
+    pub fn annotations(&self) -> Vec<Annotation> {
+        let Some(Value::Array(entries)) = self
+            .data
+            .as_ref()
+            .and_then(|data| data.get(ANNOTATIONS_KEY))
+        else {
+            return Vec::new();
+        };
+        entries
+            .iter()
+            .filter_map(|entry| match Annotation::deserialize(entry) {
+                Ok(annotation) => Some(annotation),
+                Err(error) => {
+                    log::warn!(
+                        "ignoring invalid annotation of host `{}`: {error}",
+                        self.name
+                    );
+                    None
+                }
+            })
+            .collect()
+    }
+
+    pub fn annotation(&self, key: &str) -> Option<Annotation> {
+        self.annotations()
+            .into_iter()
+            .find(|annotation| annotation.key == key)
+    }
+
+    /// Adds `annotation` to the host data, replacing one with the same key.
+    ///
+    /// Host data that is not an object is replaced by an object.
+    pub fn annotate(&mut self, annotation: Annotation) {
+        let data = self
+            .data
+            .get_or_insert_with(|| Data::new(Value::Object(Default::default())));
+        if !data.is_object() {
+            log::warn!(
+                "replacing non-object data of host `{}` to annotate it",
+                self.name
+            );
+            **data = Value::Object(Default::default());
+        }
+        let entries = data
+            .as_object_mut()
+            .expect("host data is an object")
+            .entry(ANNOTATIONS_KEY)
+            .or_insert_with(|| Value::Array(Vec::new()));
+        if !entries.is_array() {
+            *entries = Value::Array(Vec::new());
+        }
+        let entries = entries.as_array_mut().expect("annotations are an array");
+        entries.retain(|entry| entry.get("key").and_then(Value::as_str) != Some(&annotation.key));
+        entries.push(serde_json::to_value(annotation).expect("annotations serialize"));
+    }
+
+    /// Removes the annotation with `key` and returns `true` if there was one.
+    pub fn remove_annotation(&mut self, key: &str) -> bool {
+        if self.annotation(key).is_none() {
+            return false;
+        }
+        if let Some(Value::Array(entries)) = self
+            .data
+            .as_mut()
+            .and_then(|data| data.get_mut(ANNOTATIONS_KEY))
+        {
+            entries.retain(|entry| entry.get("key").and_then(Value::as_str) != Some(key));
+        }
+        true
+    }
+}
+
+impl Inventory {
+    /// Hosts annotated with `key`, in natural order.
+    pub fn annotated_hosts(&self, key: &str) -> Vec<&Host> {
+        self.hosts
+            .values()
+            .filter(|host| host.annotation(key).is_some())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_annotate_hosts() {
+        let mut inventory = Inventory::new();
+        inventory.hosts.add_host(Host::new("r1"));
+        inventory.hosts.add_host(Host::new("r2"));
+
+        let r1 = &mut inventory.hosts["r1"];
+        r1.annotate(Annotation::new("needs-reboot", Severity::Warning));
+        r1.annotate(Annotation::new("cert-expires", Severity::Info).value("2025-01-01"));
+        r1.annotate(Annotation::new("cert-expires", Severity::Error).value("2024-01-01"));
+        assert_eq!(
+            r1.annotation("cert-expires"),
+            Some(Annotation::new("cert-expires", Severity::Error).value("2024-01-01"))
+        );
+        assert_eq!(
+            r1.data.as_ref().unwrap()["annotations"][0],
+            json!({ "key": "needs-reboot", "severity": "warning" })
+        );
+
+        let names: Vec<&str> = inventory
+            .annotated_hosts("needs-reboot")
+            .iter()
+            .map(|host| host.name.as_str())
+            .collect();
+        assert_eq!(names, ["r1"]);
+
+        let r1 = &mut inventory.hosts["r1"];
+        assert!(r1.remove_annotation("needs-reboot"));
+        assert!(!r1.remove_annotation("needs-reboot"));
+        assert_eq!(r1.annotations().len(), 1);
+    }
+}
@@ -0,0 +1,423 @@
+use super::{ConnectionOptions, Data, Defaults, Extras, Group, Groups, Host, Hosts, Inventory};
+use crate::CustomTreeMap;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
+
+/// Fragments marking a key as secret wherever they appear in it, once the
+/// key is lower-cased and stripped of punctuation, e.g. `snmpCommunity`,
+/// `auth-key` or `passwd`.
+const SECRET_KEY_FRAGMENTS: &[&str] = &[
+    "password",
+    "passwd",
+    "passphrase",
+    "secret",
+    "token",
+    "community",
+    "authkey",
+    "apikey",
+    "privkey",
+    "privatekey",
+    "presharedkey",
+];
+
+/// Short words that only mark a key as secret as a whole segment, so
+/// `bgp_key` and `wifiPsk` match but `keyboard` and `passenger` do not.
+const SECRET_KEY_SEGMENTS: &[&str] = &["key", "pass", "pwd", "psk"];
+
+/// Options controlling [`Inventory::anonymize_with`].
+#[derive(Debug, Clone, Default)]
+pub struct AnonymizeOptions {
+    /// When set, only these top-level `Data` keys are kept on hosts and groups.
+    pub data_allow_list: Option<Vec<String>>,
+}
+
+impl AnonymizeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn data_allow_list<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.data_allow_list = Some(keys.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+/// Keeps the pseudonym tables so the same original value always maps to
+/// the same replacement across hosts, groups and data.
+#[derive(Default)]
+struct Pseudonymizer {
+    names: HashMap<String, String>,
+    ips: HashMap<IpAddr, IpAddr>,
+    ipv4: u64,
+    ipv6: u128,
+}
+
+impl Pseudonymizer {
+    /// Registers the host names and hostnames of `host`, so the same
+    /// names found in data are replaced, wherever they appear.
+    fn register(&mut self, host: &Host) {
+        self.name(&host.name);
+        if let Some(hostname) = &host.hostname {
+            self.name(hostname);
+        }
+    }
+
+    fn name(&mut self, name: &str) -> String {
+        if let Ok(ip) = name.parse::<IpAddr>() {
+            return self.ip(ip).to_string();
+        }
+        let next = self.names.len() + 1;
+        self.names
+            .entry(name.to_string())
+            .or_insert_with(|| format!("host{next}"))
+            .clone()
+    }
+
+    /// IPv4 addresses are numbered from 10.0.0.1 and IPv6 addresses from
+    /// 2001:db8::1, in the documentation prefix. IPv4 numbers continue past
+    /// 10.255.255.255 and wrap around the address space, which has room for
+    /// every distinct address, so pseudonyms never repeat.
+    fn ip(&mut self, ip: IpAddr) -> IpAddr {
+        if let Some(pseudonym) = self.ips.get(&ip) {
+            return *pseudonym;
+        }
+        let pseudonym = match ip {
+            IpAddr::V4(_) => {
+                self.ipv4 += 1;
+                IpAddr::V4(Ipv4Addr::from((0x0A00_0000 + self.ipv4) as u32))
+            }
+            IpAddr::V6(_) => {
+                self.ipv6 += 1;
+                IpAddr::V6(Ipv6Addr::from(0x2001_0db8 << 96 | self.ipv6))
+            }
+        };
+        self.ips.insert(ip, pseudonym);
+        pseudonym
+    }
+
+    fn value(&mut self, value: &Value) -> Value {
+        match value {
+            Value::String(s) => match s.parse::<IpAddr>() {
+                Ok(ip) => Value::String(self.ip(ip).to_string()),
+                Err(_) => match self.names.get(s) {
+                    Some(pseudonym) => Value::String(pseudonym.clone()),
+                    None => value.clone(),
+                },
+            },
+            Value::Array(list) => Value::Array(list.iter().map(|v| self.value(v)).collect()),
+            Value::Object(map) => Value::Object(
+                map.iter()
+                    .filter(|(key, _)| !is_secret_key(key))
+                    .map(|(key, v)| (key.clone(), self.value(v)))
+                    .collect(),
+            ),
+            _ => value.clone(),
+        }
+    }
+
+    fn data(&mut self, data: &Data, options: &AnonymizeOptions) -> Data {
+        let mut value = self.value(data);
+        if let (Some(allowed), Value::Object(map)) = (&options.data_allow_list, &mut value) {
+            map.retain(|key, _| allowed.contains(key));
+        }
+        Data::new(value)
+    }
+
+    fn connection_options(
+        &mut self,
+        options: &CustomTreeMap<ConnectionOptions>,
+    ) -> CustomTreeMap<ConnectionOptions> {
+        let mut anonymized = CustomTreeMap::new();
+        for (name, opts) in options.iter() {
+            let opts = ConnectionOptions {
+                hostname: opts.hostname.as_deref().map(|h| self.name(h)),
+                port: opts.port,
+                username: None,
+                password: None,
+                platform: opts.platform.clone(),
+                extras: opts.extras.as_ref().map(|e| Extras(self.value(e))),
+                credentials: None,
+                tls: opts.tls.clone(),
+                proxy: None,
+            };
+            anonymized.insert(name.as_str(), opts);
+        }
+        anonymized
+    }
+
+    fn host(
+        &mut self,
+        host: &Host,
+        defaults: &mut HashMap<*const Defaults, Arc<Defaults>>,
+        options: &AnonymizeOptions,
+    ) -> Host {
+        let mut anonymized = Host::new(&self.name(&host.name));
+        anonymized.hostname = host.hostname.as_deref().map(|h| self.name(h));
+        anonymized.port = host.port;
+        anonymized.platform = host.platform.clone();
+        anonymized.groups = host.groups.clone();
+        anonymized.data = host.data.as_ref().map(|d| self.data(d, options));
+        anonymized.connection_options = host
+            .connection_options
+            .as_ref()
+            .map(|o| self.connection_options(o));
+        // Hosts sharing one `Arc<Defaults>` keep sharing the anonymized copy.
+        anonymized.defaults = host.defaults.as_ref().map(|d| {
+            defaults
+                .entry(Arc::as_ptr(d))
+                .or_insert_with(|| Arc::new(Defaults(self.value(d))))
+                .clone()
+        });
+        anonymized
+    }
+
+    fn group(
+        &mut self,
+        group: &Group,
+        defaults: &mut HashMap<*const Defaults, Arc<Defaults>>,
+        options: &AnonymizeOptions,
+    ) -> Group {
+        Group {
+            hostname: group.hostname.as_deref().map(|h| self.name(h)),
+            port: group.port,
+            username: None,
+            password: None,
+            platform: group.platform.clone(),
+            groups: group.groups.clone(),
+            data: group.data.as_ref().map(|d| self.data(d, options)),
+            connection_options: group
+                .connection_options
+                .as_ref()
+                .map(|o| self.connection_options(o)),
+            defaults: group.defaults.as_ref().map(|d| {
+                defaults
+                    .entry(Arc::as_ptr(d))
+                    .or_insert_with(|| Arc::new(Defaults(self.value(d))))
+                    .clone()
+            }),
+        }
+    }
+}
+
+/// Returns `true` if values under `key` look like secrets. Used by
+/// [`Inventory::anonymize`] and the network defaults of the `redact`
+/// module.
+///
+/// Matching ignores case. A key is secret when it contains one of the
+/// unambiguous fragments such as `password`, `community` or `authkey`, or
+/// when one of its segments, split on punctuation and lower to upper case
+/// changes, is a short word such as `key` or `psk`.
+///
+/// ```
+/// # use genja_core::inventory::is_secret_key;
+/// assert!(is_secret_key("SNMPCommunity"));
+/// assert!(is_secret_key("bgp_authKey"));
+/// assert!(!is_secret_key("keyboard"));
+/// ```
+pub fn is_secret_key(key: &str) -> bool {
+    let compact: String = key
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if SECRET_KEY_FRAGMENTS
+        .iter()
+        .any(|fragment| compact.contains(fragment))
+    {
+        return true;
+    }
+
+    let mut segments = Vec::new();
+    let mut segment = String::new();
+    let mut previous_lower = false;
+    for c in key.chars() {
+        if !c.is_alphanumeric() || (c.is_uppercase() && previous_lower) {
+            segments.push(std::mem::take(&mut segment));
+        }
+        if c.is_alphanumeric() {
+            segment.extend(c.to_lowercase());
+        }
+        previous_lower = c.is_lowercase() || c.is_ascii_digit();
+    }
+    segments.push(segment);
+    segments.iter().any(|segment| {
+        let singular = segment.strip_suffix('s').unwrap_or(segment);
+        SECRET_KEY_SEGMENTS.contains(&segment.as_str()) || SECRET_KEY_SEGMENTS.contains(&singular)
+    })
+}
+
+impl Inventory {
+    /// Returns an anonymized copy of the inventory using the default options.
+    ///
+    /// See [`Inventory::anonymize_with`].
+    pub fn anonymize(&self) -> Inventory {
+        self.anonymize_with(&AnonymizeOptions::default())
+    }
+
+    /// Returns a copy of the inventory that is safe to attach to bug reports.
+    ///
+    /// * Host names and hostnames are replaced with `hostN` pseudonyms.
+    /// * IP addresses (hostnames, data and extras values) are replaced with
+    ///   addresses from private/documentation ranges.
+    /// * Data and extras values equal to a host name or hostname of the
+    ///   inventory get the same pseudonym.
+    /// * Usernames, passwords and secret-looking data keys are removed.
+    ///
+    /// The same original value always maps to the same pseudonym, so the
+    /// relationships between hosts are preserved. Group names and platforms
+    /// are kept as-is. Transform functions and open connections are not copied.
+    pub fn anonymize_with(&self, options: &AnonymizeOptions) -> Inventory {
+        let mut pseudonymizer = Pseudonymizer::default();
+        let mut defaults = HashMap::new();
+
+        for host in self.hosts.values() {
+            pseudonymizer.register(host);
+        }
+        let mut hosts = Hosts::new();
+        for host in self.hosts.values() {
+            hosts.add_host(pseudonymizer.host(host, &mut defaults, options));
+        }
+
+        let groups = self.groups.as_ref().map(|groups| {
+            let mut anonymized = CustomTreeMap::new();
+            for (name, group) in groups.iter() {
+                anonymized.insert(
+                    name.as_str(),
+                    pseudonymizer.group(group, &mut defaults, options),
+                );
+            }
+            Groups(anonymized)
+        });
+
+        Inventory {
+            hosts,
+            groups,
+            defaults: self
+                .defaults
+                .as_ref()
+                .map(|d| Defaults(pseudonymizer.value(d))),
+            group_resolution: self.group_resolution,
+            ..Inventory::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::BaseBuilderHost;
+    use serde_json::json;
+
+    fn inventory() -> Inventory {
+        let mut hosts = Hosts::new();
+        hosts.add_host(
+            Host::builder("core-rtr1.corp.example")
+                .hostname("172.16.4.1")
+                .username("admin")
+                .password("hunter2")
+                .platform("cisco_ios")
+                .data(Data::new(json!({
+                    "site": "hq",
+                    "loopback": "172.16.4.1",
+                    "snmp_community": "s3cr3t",
+                    "peer": "172.16.4.2",
+                    "peer_name": "core-rtr2.corp.example",
+                    "keyboard": "us",
+                    "apiKey": "abc"
+                })))
+                .build(),
+        );
+        hosts.add_host(
+            Host::builder("core-rtr2.corp.example")
+                .hostname("172.16.4.2")
+                .data(Data::new(json!({ "site": "hq" })))
+                .build(),
+        );
+        Inventory::builder().hosts(hosts).build()
+    }
+
+    #[test]
+    fn test_anonymize_is_consistent_and_strips_secrets() {
+        let anonymized = inventory().anonymize();
+
+        let names: Vec<&str> = anonymized.hosts.keys_str().collect();
+        assert_eq!(names, vec!["host1", "host2"]);
+
+        let first = anonymized.hosts.get("host1").unwrap();
+        assert_eq!(first.hostname.as_deref(), Some("10.0.0.1"));
+        assert_eq!(first.username, None);
+        assert_eq!(first.password, None);
+        assert_eq!(first.platform.as_deref(), Some("cisco_ios"));
+
+        let data = first.data.as_ref().unwrap();
+        assert_eq!(data["loopback"], json!("10.0.0.1"));
+        assert_eq!(data["peer"], json!("10.0.0.2"));
+        assert_eq!(data["peer_name"], json!("host2"));
+        assert!(data.get("snmp_community").is_none());
+        assert!(data.get("apiKey").is_none());
+        assert_eq!(data["keyboard"], json!("us"));
+
+        let second = anonymized.hosts.get("host2").unwrap();
+        assert_eq!(second.hostname.as_deref(), Some("10.0.0.2"));
+    }
+
+    #[test]
+    fn test_anonymize_data_allow_list() {
+        let options = AnonymizeOptions::new().data_allow_list(["site"]);
+        let anonymized = inventory().anonymize_with(&options);
+        let data = anonymized
+            .hosts
+            .get("host1")
+            .unwrap()
+            .data
+            .as_ref()
+            .unwrap();
+        assert_eq!(**data, json!({ "site": "hq" }));
+    }
+
+    #[test]
+    fn test_secret_key_segments() {
+        for key in [
+            "password",
+            "snmp_community",
+            "bgp-auth-key",
+            "apiKey",
+            "TOKEN",
+            "authkey",
+            "snmpcommunity",
+            "SNMPCommunity",
+            "passwd",
+            "ike_psk",
+        ] {
+            assert!(is_secret_key(key), "{key}");
+        }
+        for key in ["keyboard", "monkey", "site", "keyring", "passenger"] {
+            assert!(!is_secret_key(key), "{key}");
+        }
+    }
+
+    #[test]
+    fn test_ip_pseudonyms_do_not_wrap() {
+        let mut pseudonymizer = Pseudonymizer {
+            ipv4: 0x00FF_FFFE,
+            ipv6: u16::MAX as u128,
+            ..Pseudonymizer::default()
+        };
+        let v4: Vec<IpAddr> = ["192.0.2.1", "192.0.2.2"]
+            .iter()
+            .map(|ip| pseudonymizer.ip(ip.parse().unwrap()))
+            .collect();
+        assert_eq!(
+            v4,
+            ["10.255.255.255", "11.0.0.0"].map(|ip| ip.parse::<IpAddr>().unwrap())
+        );
+        let v6 = pseudonymizer.ip("fe80::1".parse().unwrap());
+        assert_eq!(v6, "2001:db8::1:0".parse::<IpAddr>().unwrap());
+    }
+}
@@ -0,0 +1,898 @@
+use super::{
+    Data, Defaults, Group, Groups, Host, Inventory, InventoryError, LoadStage, ParentGroups,
+};
+use crate::CustomTreeMap;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+/// Entries generated by [`Inventory::to_ansible_json`], which inventory
+/// groups must not use as names.
+const RESERVED_GROUPS: [&str; 3] = ["all", "ungrouped", "_meta"];
+
+/// Maps the connection fields shared by hosts and groups onto Ansible's
+/// `ansible_*` variables and merges the data keys next to them.
+fn ansible_vars(
+    hostname: Option<&str>,
+    port: Option<u16>,
+    username: Option<&str>,
+    password: Option<&str>,
+    platform: Option<&str>,
+    data: Option<&Data>,
+) -> Map<String, Value> {
+    let mut vars = Map::new();
+    match data.map(|data| &**data) {
+        Some(Value::Object(data)) => vars.extend(data.clone()),
+        Some(Value::Null) | None => {}
+        Some(other) => {
+            vars.insert("data".to_string(), other.clone());
+        }
+    }
+    let fields = [
+        ("ansible_host", hostname.map(Value::from)),
+        ("ansible_port", port.map(Value::from)),
+        ("ansible_user", username.map(Value::from)),
+        ("ansible_password", password.map(Value::from)),
+        ("ansible_network_os", platform.map(Value::from)),
+    ];
+    for (key, value) in fields {
+        if let Some(value) = value {
+            vars.insert(key.to_string(), value);
+        }
+    }
+    vars
+}
+
+impl Inventory {
+    /// Exports the inventory in Ansible's dynamic inventory JSON format.
+    ///
+    /// * Host fields become `ansible_host`, `ansible_port`, `ansible_user`,
+    ///   `ansible_password` and `ansible_network_os` in `_meta.hostvars`,
+    ///   next to the host's data keys.
+    /// * Each group lists its member hosts, its vars and the groups that
+    ///   declare it as a parent under `children`.
+    /// * Groups without parents are children of `all`, hosts without groups
+    ///   are placed in `ungrouped`, and the inventory defaults become
+    ///   `all.vars`.
+    ///
+    /// The output can be printed from a script passed to `ansible -i`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InventoryError::Validation`] if a group is named `all`,
+    /// `ungrouped` or `_meta`, which the export generates itself.
+    pub fn to_ansible_json(&self) -> Result<Value, InventoryError> {
+        let mut hostvars = Map::new();
+        let mut members: Map<String, Value> = Map::new();
+        let mut ungrouped = Vec::new();
+        let mut group_names: BTreeSet<String> = BTreeSet::new();
+
+        let mut add_member = |group: &str, host: &str| {
+            members
+                .entry(group.to_string())
+                .or_insert_with(|| Value::Array(Vec::new()))
+                .as_array_mut()
+                .expect("members are always arrays")
+                .push(Value::from(host));
+        };
+
+        for host in self.hosts.values() {
+            hostvars.insert(
+                host.name.clone(),
+                Value::Object(ansible_vars(
+                    host.hostname.as_deref(),
+                    host.port,
+                    host.username.as_deref(),
+                    host.password.as_deref(),
+                    host.platform.as_deref(),
+                    host.data.as_ref(),
+                )),
+            );
+            match host.groups.as_ref().filter(|groups| !groups.is_empty()) {
+                Some(groups) => {
+                    for group in groups.iter() {
+                        add_member(group, &host.name);
+                        group_names.insert(group.clone());
+                    }
+                }
+                None => ungrouped.push(Value::from(host.name.as_str())),
+            }
+        }
+
+        let empty_parents = ParentGroups::new();
+        let parents_of = |name: &str| {
+            self.groups
+                .as_ref()
+                .and_then(|groups| groups.get(name))
+                .and_then(|group| group.groups.as_ref())
+                .unwrap_or(&empty_parents)
+                .clone()
+        };
+        if let Some(groups) = &self.groups {
+            group_names.extend(groups.keys_str().map(str::to_string));
+            for group in groups.values() {
+                group_names.extend(group.groups.iter().flat_map(|p| p.iter().cloned()));
+            }
+        }
+        if let Some(name) = RESERVED_GROUPS
+            .iter()
+            .find(|name| group_names.contains(**name))
+        {
+            return Err(InventoryError::Validation {
+                name: name.to_string(),
+                message: "group name is reserved by the Ansible inventory format".to_string(),
+            });
+        }
+
+        let mut output = Map::new();
+        let mut top_level = Vec::new();
+        for name in &group_names {
+            let mut entry = Map::new();
+            if let Some(hosts) = members.get(name) {
+                entry.insert("hosts".to_string(), hosts.clone());
+            }
+            if let Some(group) = self.groups.as_ref().and_then(|groups| groups.get(name)) {
+                let vars = ansible_vars(
+                    group.hostname.as_deref(),
+                    group.port,
+                    group.username.as_deref(),
+                    group.password.as_deref(),
+                    group.platform.as_deref(),
+                    group.data.as_ref(),
+                );
+                if !vars.is_empty() {
+                    entry.insert("vars".to_string(), Value::Object(vars));
+                }
+            }
+            let children: Vec<Value> = group_names
+                .iter()
+                .filter(|child| parents_of(child).contains(name))
+                .map(|child| Value::from(child.as_str()))
+                .collect();
+            if !children.is_empty() {
+                entry.insert("children".to_string(), Value::Array(children));
+            }
+            if parents_of(name).is_empty() {
+                top_level.push(Value::from(name.as_str()));
+            }
+            output.insert(name.clone(), Value::Object(entry));
+        }
+
+        if !ungrouped.is_empty() {
+            output.insert("ungrouped".to_string(), json!({ "hosts": ungrouped }));
+            top_level.push(Value::from("ungrouped"));
+        }
+
+        let mut all = Map::new();
+        all.insert("children".to_string(), Value::Array(top_level));
+        if let Some(Value::Object(defaults)) = self.defaults.as_deref() {
+            all.insert("vars".to_string(), Value::Object(defaults.clone()));
+        }
+        output.insert("all".to_string(), Value::Object(all));
+        output.insert("_meta".to_string(), json!({ "hostvars": hostvars }));
+        Ok(Value::Object(output))
+    }
+}
+
+/// Hosts and groups of an Ansible inventory before they are mapped onto
+/// the inventory model.
+#[derive(Debug, Default)]
+struct AnsibleInventory {
+    /// Host vars and the groups listing the host.
+    hosts: BTreeMap<String, (Map<String, Value>, BTreeSet<String>)>,
+    /// Group vars and parent groups.
+    groups: BTreeMap<String, (Map<String, Value>, BTreeSet<String>)>,
+}
+
+impl AnsibleInventory {
+    fn group(&mut self, name: &str) -> &mut (Map<String, Value>, BTreeSet<String>) {
+        self.groups.entry(name.to_string()).or_default()
+    }
+
+    fn add_hosts(
+        &mut self,
+        pattern: &str,
+        group: &str,
+        vars: &Map<String, Value>,
+    ) -> Result<(), String> {
+        self.group(group);
+        for name in expand_host_pattern(pattern)? {
+            let (host_vars, groups) = self.hosts.entry(name).or_default();
+            host_vars.extend(vars.clone());
+            groups.insert(group.to_string());
+        }
+        Ok(())
+    }
+
+    fn add_child(&mut self, parent: &str, child: &str) {
+        self.group(parent);
+        self.group(child).1.insert(parent.to_string());
+    }
+
+    fn parse_ini(text: &str) -> Result<Self, String> {
+        enum Section {
+            Hosts(String),
+            Children(String),
+            Vars(String),
+        }
+        let mut inventory = AnsibleInventory::default();
+        let mut section = Section::Hosts("ungrouped".to_string());
+        for (index, line) in text.lines().enumerate() {
+            let error = |message: String| format!("line {}: {message}", index + 1);
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| error(format!("invalid section `{line}`")))?;
+                let group = header.split_once(':').map_or(header, |(group, _)| group);
+                inventory.group(group);
+                section = match header.split_once(':') {
+                    None => Section::Hosts(header.to_string()),
+                    Some((group, "children")) => Section::Children(group.to_string()),
+                    Some((group, "vars")) => Section::Vars(group.to_string()),
+                    Some((_, kind)) => {
+                        return Err(error(format!("unknown section type `{kind}`")));
+                    }
+                };
+                continue;
+            }
+            match &section {
+                Section::Hosts(group) => {
+                    let mut tokens = split_ini_line(line).map_err(&error)?.into_iter();
+                    let pattern = tokens.next().expect("non-empty lines have a token");
+                    let mut vars = Map::new();
+                    for token in tokens {
+                        let (key, value) = token
+                            .split_once('=')
+                            .ok_or_else(|| error(format!("expected key=value, found `{token}`")))?;
+                        vars.insert(key.to_string(), ini_literal(value));
+                    }
+                    inventory
+                        .add_hosts(&pattern, group, &vars)
+                        .map_err(&error)?;
+                }
+                Section::Children(group) => {
+                    let child = line.split(['#', ';']).next().unwrap_or_default().trim();
+                    if child.contains(char::is_whitespace) {
+                        return Err(error(format!("expected a group name, found `{line}`")));
+                    }
+                    inventory.add_child(group, child);
+                }
+                Section::Vars(group) => {
+                    let (key, value) = line
+                        .split_once('=')
+                        .ok_or_else(|| error(format!("expected key=value, found `{line}`")))?;
+                    let value = unquote(value.trim());
+                    inventory
+                        .group(group)
+                        .0
+                        .insert(key.trim().to_string(), Value::String(value));
+                }
+            }
+        }
+        Ok(inventory)
+    }
+
+    fn parse_yaml(text: &str) -> Result<Self, String> {
+        let document: Value = serde_yaml::from_str(text).map_err(|error| error.to_string())?;
+        let mut inventory = AnsibleInventory::default();
+        match document {
+            Value::Null => {}
+            Value::Object(groups) => {
+                for (name, group) in groups {
+                    inventory.parse_yaml_group(&name, group)?;
+                }
+            }
+            other => return Err(format!("expected a mapping of groups, found {other}")),
+        }
+        Ok(inventory)
+    }
+
+    fn parse_yaml_group(&mut self, name: &str, group: Value) -> Result<(), String> {
+        self.group(name);
+        let group = match group {
+            Value::Null => return Ok(()),
+            Value::Object(group) => group,
+            other => return Err(format!("group `{name}`: expected a mapping, found {other}")),
+        };
+        let as_map = |key: &str, value: Value| match value {
+            Value::Null => Ok(Map::new()),
+            Value::Object(map) => Ok(map),
+            other => Err(format!(
+                "group `{name}`: expected a mapping in `{key}`, found {other}"
+            )),
+        };
+        for (key, value) in group {
+            match key.as_str() {
+                "hosts" => {
+                    for (pattern, vars) in as_map("hosts", value)? {
+                        let vars = as_map(&pattern, vars)?;
+                        self.add_hosts(&pattern, name, &vars)?;
+                    }
+                }
+                "vars" => {
+                    let vars = as_map("vars", value)?;
+                    self.group(name).0.extend(vars);
+                }
+                "children" => {
+                    for (child, group) in as_map("children", value)? {
+                        self.add_child(name, &child);
+                        self.parse_yaml_group(&child, group)?;
+                    }
+                }
+                other => return Err(format!("group `{name}`: unknown key `{other}`")),
+            }
+        }
+        Ok(())
+    }
+
+    fn into_inventory(self) -> Result<Inventory, String> {
+        let mut inventory = Inventory::new();
+        let implicit = |group: &String| group != "all" && group != "ungrouped";
+        for (name, (vars, groups)) in self.hosts {
+            let fields = AnsibleFields::split(vars).map_err(|e| format!("host `{name}`: {e}"))?;
+            let mut host = Host::new(&name);
+            host.hostname = fields.hostname;
+            host.port = fields.port;
+            host.username = fields.username;
+            host.password = fields.password;
+            host.platform = fields.platform;
+            host.data = fields.data.map(|data| Data::new(Value::Object(data)));
+            let groups: Vec<String> = groups.into_iter().filter(implicit).collect();
+            host.groups = (!groups.is_empty()).then_some(ParentGroups(groups));
+            inventory.hosts.add_host(host);
+        }
+
+        let mut groups = CustomTreeMap::new();
+        for (name, (vars, parents)) in self.groups {
+            let fields = AnsibleFields::split(vars).map_err(|e| format!("group `{name}`: {e}"))?;
+            if name == "all" {
+                inventory.defaults = fields.into_defaults();
+                continue;
+            }
+            if name == "ungrouped" {
+                continue;
+            }
+            let parents: Vec<String> = parents.into_iter().filter(implicit).collect();
+            let group = Group {
+                hostname: fields.hostname,
+                port: fields.port,
+                username: fields.username,
+                password: fields.password,
+                platform: fields.platform,
+                groups: (!parents.is_empty()).then_some(ParentGroups(parents)),
+                data: fields.data.map(|data| Data::new(Value::Object(data))),
+                ..Group::new()
+            };
+            groups.insert(name, group);
+        }
+        if !groups.is_empty() {
+            inventory.groups = Some(Groups(groups));
+        }
+        Ok(inventory)
+    }
+}
+
+/// The `ansible_*` connection variables, split from the other vars.
+struct AnsibleFields {
+    hostname: Option<String>,
+    port: Option<u16>,
+    username: Option<String>,
+    password: Option<String>,
+    platform: Option<String>,
+    data: Option<Map<String, Value>>,
+}
+
+impl AnsibleFields {
+    fn split(mut vars: Map<String, Value>) -> Result<Self, String> {
+        let mut take = |keys: &[&str]| -> Result<Option<String>, String> {
+            let mut found = None;
+            for key in keys {
+                match vars.remove(*key) {
+                    None | Some(Value::Null) => {}
+                    Some(Value::String(value)) => found = found.or(Some(value)),
+                    Some(Value::Number(value)) => found = found.or(Some(value.to_string())),
+                    Some(other) => return Err(format!("`{key}` must be a string, found {other}")),
+                }
+            }
+            Ok(found)
+        };
+        let hostname = take(&["ansible_host", "ansible_ssh_host"])?;
+        let port = take(&["ansible_port", "ansible_ssh_port"])?
+            .map(|port| {
+                port.parse::<u16>()
+                    .map_err(|_| format!("invalid `ansible_port` `{port}`"))
+            })
+            .transpose()?;
+        let username = take(&["ansible_user", "ansible_ssh_user"])?;
+        let password = take(&["ansible_password", "ansible_ssh_pass"])?;
+        let platform = take(&["ansible_network_os"])?;
+        Ok(AnsibleFields {
+            hostname,
+            port,
+            username,
+            password,
+            platform,
+            data: (!vars.is_empty()).then_some(vars),
+        })
+    }
+
+    /// Defaults take the connection fields at the top level and the other
+    /// vars under `data`.
+    fn into_defaults(self) -> Option<Defaults> {
+        let mut defaults = Map::new();
+        let fields = [
+            ("hostname", self.hostname.map(Value::from)),
+            ("port", self.port.map(Value::from)),
+            ("username", self.username.map(Value::from)),
+            ("password", self.password.map(Value::from)),
+            ("platform", self.platform.map(Value::from)),
+            ("data", self.data.map(Value::Object)),
+        ];
+        for (key, value) in fields {
+            if let Some(value) = value {
+                defaults.insert(key.to_string(), value);
+            }
+        }
+        (!defaults.is_empty()).then(|| Defaults::new(Value::Object(defaults)))
+    }
+}
+
+/// Expands Ansible host ranges such as `web[01:20].example.com`,
+/// `db[a:c]` or `r[1:10:2]`. Numeric ranges with a leading zero keep their
+/// width.
+fn expand_host_pattern(pattern: &str) -> Result<Vec<String>, String> {
+    let Some(open) = pattern.find('[') else {
+        return Ok(vec![pattern.to_string()]);
+    };
+    let invalid = || format!("invalid host range in `{pattern}`");
+    let close = pattern[open..].find(']').ok_or_else(invalid)? + open;
+    let (prefix, range, suffix) = (
+        &pattern[..open],
+        &pattern[open + 1..close],
+        &pattern[close + 1..],
+    );
+    let mut bounds = range.split(':');
+    let (start, end) = match (bounds.next(), bounds.next()) {
+        (Some(start), Some(end)) => (start, end),
+        _ => return Err(invalid()),
+    };
+    let stride = match bounds.next() {
+        Some(stride) => stride
+            .parse::<usize>()
+            .ok()
+            .filter(|s| *s > 0)
+            .ok_or_else(invalid)?,
+        None => 1,
+    };
+    if bounds.next().is_some() {
+        return Err(invalid());
+    }
+
+    let items: Vec<String> = match (start.parse::<u64>(), end.parse::<u64>()) {
+        (Ok(first), Ok(last)) if first <= last => {
+            let width = if start.len() > 1 && start.starts_with('0') {
+                if start.len() != end.len() {
+                    return Err(format!(
+                        "host range `{range}` in `{pattern}` has bounds of different widths"
+                    ));
+                }
+                start.len()
+            } else {
+                0
+            };
+            (first..=last)
+                .step_by(stride)
+                .map(|index| format!("{index:0width$}"))
+                .collect()
+        }
+        _ => match (start.as_bytes(), end.as_bytes()) {
+            ([first], [last]) if first.is_ascii_alphabetic() && first <= last => (*first..=*last)
+                .step_by(stride)
+                .map(|letter| char::from(letter).to_string())
+                .collect(),
+            _ => return Err(invalid()),
+        },
+    };
+
+    let tails = expand_host_pattern(suffix)?;
+    Ok(items
+        .iter()
+        .flat_map(|item| {
+            tails
+                .iter()
+                .map(move |tail| format!("{prefix}{item}{tail}"))
+        })
+        .collect())
+}
+
+/// Splits a host line on whitespace, keeping quoted values together and
+/// dropping a trailing `#` comment.
+fn split_ini_line(line: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut quote = None;
+    for c in line.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => {
+                quote = None;
+                token.push(c);
+            }
+            (Some(_), c) => token.push(c),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                token.push(c);
+            }
+            (None, '#') if token.is_empty() => break,
+            (None, c) if c.is_whitespace() => {
+                if !token.is_empty() {
+                    tokens.push(std::mem::take(&mut token));
+                }
+            }
+            (None, c) => token.push(c),
+        }
+    }
+    if quote.is_some() {
+        return Err(format!("unterminated quote in `{line}`"));
+    }
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+fn unquote(value: &str) -> String {
+    for quote in ['"', '\''] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|value| value.strip_suffix(quote))
+        {
+            return inner.to_string();
+        }
+    }
+    value.to_string()
+}
+
+/// Host vars given inline are literals: numbers and booleans are typed,
+/// everything else is a string.
+fn ini_literal(value: &str) -> Value {
+    if value.starts_with(['"', '\'']) {
+        return Value::String(unquote(value));
+    }
+    match value {
+        "True" | "true" => return Value::Bool(true),
+        "False" | "false" => return Value::Bool(false),
+        _ => {}
+    }
+    if let Ok(number) = value.parse::<i64>() {
+        return Value::from(number);
+    }
+    match value.parse::<f64>() {
+        Ok(number) if number.is_finite() => Value::from(number),
+        _ => Value::String(value.to_string()),
+    }
+}
+
+fn ansible_error(source: &str, message: String) -> InventoryError {
+    InventoryError::Load {
+        stage: LoadStage::Parse,
+        message: format!("`{source}`: {message}"),
+    }
+}
+
+impl Inventory {
+    /// Imports an Ansible inventory file. Files ending in `.yml`, `.yaml`
+    /// or `.json` are read as YAML, anything else as INI.
+    ///
+    /// See [`Inventory::from_ansible_ini`] for how Ansible concepts are
+    /// mapped.
+    pub fn from_ansible(path: impl AsRef<Path>) -> Result<Inventory, InventoryError> {
+        let path = path.as_ref();
+        let source = path.display().to_string();
+        let text = fs::read_to_string(path).map_err(|e| ansible_error(&source, e.to_string()))?;
+        let is_yaml = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| matches!(extension, "yml" | "yaml" | "json"));
+        let inventory = if is_yaml {
+            AnsibleInventory::parse_yaml(&text)
+        } else {
+            AnsibleInventory::parse_ini(&text)
+        };
+        inventory
+            .and_then(AnsibleInventory::into_inventory)
+            .map_err(|message| ansible_error(&source, message))
+    }
+
+    /// Imports an Ansible INI inventory.
+    ///
+    /// * `[group]`, `[group:children]` and `[group:vars]` sections become
+    ///   groups, their parents and their vars. Hosts before the first
+    ///   section are ungrouped.
+    /// * Host ranges such as `web[01:20].example.com` are expanded.
+    /// * `ansible_host`, `ansible_port`, `ansible_user`, `ansible_password`
+    ///   and `ansible_network_os` (and their `ansible_ssh_*` forms) become
+    ///   the hostname, port, username, password and platform. Other vars
+    ///   become data.
+    /// * The vars of `all` become the defaults.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use genja_core::inventory::Inventory;
+    /// let inventory = Inventory::from_ansible_ini(
+    ///     "
+    /// [web]
+    /// web[01:03].example.com ansible_port=2222
+    ///
+    /// [dc1:children]
+    /// web
+    /// ",
+    /// )
+    /// .unwrap();
+    ///
+    /// let web02 = inventory.hosts.get("web02.example.com").unwrap();
+    /// assert_eq!(web02.port, Some(2222));
+    /// assert_eq!(inventory.groups.unwrap().get("web").unwrap().groups.as_ref().unwrap()[0], "dc1");
+    /// ```
+    pub fn from_ansible_ini(text: &str) -> Result<Inventory, InventoryError> {
+        AnsibleInventory::parse_ini(text)
+            .and_then(AnsibleInventory::into_inventory)
+            .map_err(|message| ansible_error("ansible inventory", message))
+    }
+
+    /// Imports an Ansible YAML inventory, mapped like
+    /// [`Inventory::from_ansible_ini`].
+    pub fn from_ansible_yaml(text: &str) -> Result<Inventory, InventoryError> {
+        AnsibleInventory::parse_yaml(text)
+            .and_then(AnsibleInventory::into_inventory)
+            .map_err(|message| ansible_error("ansible inventory", message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::{BaseBuilderHost, Hosts};
+
+    #[test]
+    fn test_to_ansible_json() {
+        let mut hosts = Hosts::new();
+        hosts.add_host(
+            Host::builder("r1")
+                .hostname("10.0.0.1")
+                .port(22)
+                .platform("eos")
+                .group("core")
+                .data_json(json!({ "role": "spine" }))
+                .build(),
+        );
+        hosts.add_host(Host::builder("lab1").build());
+
+        let mut groups = CustomTreeMap::new();
+        groups.insert(
+            "core",
+            Group {
+                groups: Some(ParentGroups(vec!["dc1".to_string()])),
+                platform: Some("eos".to_string()),
+                ..Group::new()
+            },
+        );
+        groups.insert(
+            "dc1",
+            Group {
+                data: Some(Data::new(json!({ "ntp": "192.0.2.1" }))),
+                ..Group::new()
+            },
+        );
+        let inventory = Inventory {
+            groups: Some(Groups(groups)),
+            defaults: Some(serde_json::from_value(json!({ "timeout": 30 })).unwrap()),
+            ..Inventory::builder().hosts(hosts).build()
+        };
+
+        let exported = inventory.to_ansible_json().unwrap();
+        assert_eq!(
+            exported["_meta"]["hostvars"]["r1"],
+            json!({
+                "role": "spine",
+                "ansible_host": "10.0.0.1",
+                "ansible_port": 22,
+                "ansible_network_os": "eos"
+            })
+        );
+        assert_eq!(exported["core"]["hosts"], json!(["r1"]));
+        assert_eq!(
+            exported["core"]["vars"],
+            json!({ "ansible_network_os": "eos" })
+        );
+        assert_eq!(exported["dc1"]["children"], json!(["core"]));
+        assert_eq!(exported["dc1"]["vars"], json!({ "ntp": "192.0.2.1" }));
+        assert_eq!(exported["ungrouped"]["hosts"], json!(["lab1"]));
+        assert_eq!(exported["all"]["children"], json!(["dc1", "ungrouped"]));
+        assert_eq!(exported["all"]["vars"], json!({ "timeout": 30 }));
+    }
+
+    #[test]
+    fn test_to_ansible_json_reserved_groups() {
+        for reserved in ["all", "ungrouped", "_meta"] {
+            let mut hosts = Hosts::new();
+            hosts.add_host(Host::builder("r1").group(reserved).build());
+            let inventory = Inventory::builder().hosts(hosts).build();
+            match inventory.to_ansible_json() {
+                Err(InventoryError::Validation { name, .. }) => assert_eq!(name, reserved),
+                other => panic!("expected a validation error, got {other:?}"),
+            }
+        }
+
+        let mut groups = CustomTreeMap::new();
+        groups.insert(
+            "core",
+            Group {
+                groups: Some(ParentGroups(vec!["all".to_string()])),
+                ..Group::new()
+            },
+        );
+        let inventory = Inventory {
+            groups: Some(Groups(groups)),
+            ..Inventory::builder().build()
+        };
+        assert!(inventory.to_ansible_json().is_err());
+    }
+
+    #[test]
+    fn test_from_ansible_ini() {
+        let inventory = Inventory::from_ansible_ini(
+            r#"
+    lab1 ansible_host=192.0.2.10
+
+    [spines]
+    spine[1:2] ansible_network_os=eos ansible_port=22 rack="A 1"
+
+    [leaves]
+    leaf[a:b].dc1 # comment
+    leaf[01:03:2].dc1 enabled=True weight=1.5
+
+    [dc1:children]
+    spines # spine switches
+    leaves ; leaf switches
+
+    [dc1:vars]
+    ntp_server=10.0.0.1
+    ansible_user=netops
+
+    [all:vars]
+    ansible_user=admin
+    timeout=30
+"#,
+        )
+        .unwrap();
+
+        let names: Vec<&str> = inventory.hosts.keys_str().collect();
+        assert_eq!(
+            names,
+            [
+                "lab1",
+                "leaf01.dc1",
+                "leaf03.dc1",
+                "leafa.dc1",
+                "leafb.dc1",
+                "spine1",
+                "spine2"
+            ]
+        );
+        let spine1 = &inventory.hosts["spine1"];
+        assert_eq!(spine1.platform.as_deref(), Some("eos"));
+        assert_eq!(spine1.port, Some(22));
+        assert_eq!(spine1.groups.as_ref().unwrap()[0], "spines");
+        assert_eq!(**spine1.data.as_ref().unwrap(), json!({ "rack": "A 1" }));
+        assert_eq!(
+            **inventory.hosts["leaf03.dc1"].data.as_ref().unwrap(),
+            json!({ "enabled": true, "weight": 1.5 })
+        );
+        assert_eq!(
+            inventory.hosts["lab1"].hostname.as_deref(),
+            Some("192.0.2.10")
+        );
+        assert!(inventory.hosts["lab1"].groups.is_none());
+
+        let groups = inventory.groups.as_ref().unwrap();
+        let dc1 = groups.get("dc1").unwrap();
+        assert_eq!(dc1.username.as_deref(), Some("netops"));
+        assert_eq!(
+            **dc1.data.as_ref().unwrap(),
+            json!({ "ntp_server": "10.0.0.1" })
+        );
+        assert_eq!(
+            groups.get("leaves").unwrap().groups.as_ref().unwrap()[0],
+            "dc1"
+        );
+        assert_eq!(
+            **inventory.defaults.as_ref().unwrap(),
+            json!({ "username": "admin", "data": { "timeout": "30" } })
+        );
+
+        let params = inventory.resolve_connection_params(&inventory.hosts["leafa.dc1"], "ssh");
+        assert_eq!(params.username.as_deref(), Some("netops"));
+    }
+
+    #[test]
+    fn test_from_ansible_yaml() {
+        let inventory = Inventory::from_ansible_yaml(
+            "
+    all:
+      vars:
+        ansible_connection: network_cli
+      children:
+        dc1:
+          vars: { site: ams }
+          children:
+            spines:
+              hosts:
+                spine[01:02]:
+                  ansible_network_os: eos
+            leaves:
+              hosts:
+                leaf01:
+      hosts:
+        mgmt1: { ansible_host: 192.0.2.1 }
+",
+        )
+        .unwrap();
+        assert_eq!(inventory.hosts.len(), 4);
+        assert_eq!(inventory.hosts["spine02"].platform.as_deref(), Some("eos"));
+        assert!(inventory.hosts["mgmt1"].groups.is_none());
+        let groups = inventory.groups.as_ref().unwrap();
+        assert!(groups.get("dc1").unwrap().groups.is_none());
+        assert_eq!(
+            groups.get("spines").unwrap().groups.as_ref().unwrap()[0],
+            "dc1"
+        );
+        assert_eq!(
+            inventory.defaults.as_ref().unwrap()["data"]["ansible_connection"],
+            "network_cli"
+        );
+    }
+
+    #[test]
+    fn test_ansible_import_errors() {
+        let error = Inventory::from_ansible_ini(
+            "[web]
+    web[01:2]
+",
+        )
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .ends_with("line 2: host range `01:2` in `web[01:2]` has bounds of different widths"));
+        let error = Inventory::from_ansible_ini(
+            "[web:hosts]
+",
+        )
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .ends_with("line 1: unknown section type `hosts`"));
+        let error = Inventory::from_ansible_ini(
+            "r1 ansible_port=ssh
+",
+        )
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .ends_with("host `r1`: invalid `ansible_port` `ssh`"));
+        let error = Inventory::from_ansible_ini(
+            "[dc1:children]
+    spines leaves
+",
+        )
+        .unwrap_err();
+        assert!(error
+            .to_string()
+            .ends_with("line 2: expected a group name, found `spines leaves`"));
+    }
+}
@@ -0,0 +1,498 @@
+use super::{Connection, ConnectionKey, ConnectionManager};
+use crate::connections::{DeviceError, DeviceErrorKind, RetryPolicy};
+use std::fmt;
+use std::path::Path;
+
+/// Connections that can run a command and return its output.
+pub trait SupportsExec: Connection {
+    fn exec(&mut self, command: &str) -> Result<String, String>;
+}
+
+/// Connections that can copy files to and from the device.
+pub trait SupportsFileTransfer: Connection {
+    fn put(&mut self, local: &Path, remote: &str) -> Result<(), String>;
+
+    fn get(&mut self, remote: &str, local: &Path) -> Result<(), String>;
+}
+
+/// Connections with candidate configuration sessions.
+pub trait SupportsConfigSession: Connection {
+    fn load_config(&mut self, config: &str) -> Result<(), String>;
+
+    /// Returns the diff between the candidate and the running configuration.
+    fn diff(&mut self) -> Result<String, String>;
+
+    fn commit(&mut self) -> Result<(), String>;
+
+    fn discard(&mut self) -> Result<(), String>;
+
+    /// Returns `true` if the platform can lock the configuration against
+    /// other sessions, e.g. with a NETCONF datastore lock or
+    /// `configure exclusive`.
+    fn supports_lock(&self) -> bool {
+        false
+    }
+
+    fn lock(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn unlock(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Checks the candidate configuration before it is committed, e.g. with
+    /// a NETCONF `<validate>`.
+    fn validate(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Loads and commits `config`, returning the diff it applied.
+    ///
+    /// When the platform [supports locking](Self::supports_lock) the
+    /// configuration is locked first, retrying with `retry` while another
+    /// session holds the lock. On failure the candidate is discarded, and
+    /// the lock is released whether the commit succeeded or not. Once the
+    /// commit succeeded the change is live, so a failure to unlock is only
+    /// logged.
+    fn apply_config(&mut self, config: &str, retry: &RetryPolicy) -> Result<String, DeviceError> {
+        let batch = self.apply_configs(&[config], retry);
+        match batch.error {
+            Some(error) => Err(error),
+            None => Ok(batch.diff.unwrap_or_default()),
+        }
+    }
+
+    /// Loads every edit into one candidate, validates it and commits once,
+    /// locking like [`apply_config`](Self::apply_config).
+    ///
+    /// Loading stops at the first rejected edit; nothing is committed
+    /// unless every edit loaded and the candidate validated.
+    fn apply_configs(&mut self, edits: &[&str], retry: &RetryPolicy) -> BatchOutcome {
+        let mut outcome = BatchOutcome::default();
+        let locked = self.supports_lock();
+        if locked {
+            if let Err(error) = retry.run(|_| self.lock()) {
+                outcome.error = Some(error);
+                return outcome;
+            }
+        }
+
+        for edit in edits {
+            let result = self.load_config(edit).map_err(DeviceError::classify);
+            let failed = result.is_err();
+            outcome.edits.push(result);
+            if failed {
+                break;
+            }
+        }
+        let loaded = outcome.edits.len() == edits.len() && outcome.edits.iter().all(Result::is_ok);
+        let result = if loaded {
+            self.validate()
+                .and_then(|()| self.diff())
+                .and_then(|diff| self.commit().map(|()| diff))
+                .map_err(DeviceError::classify)
+        } else {
+            Err(DeviceError::new(
+                DeviceErrorKind::CommandRejected,
+                format!(
+                    "edit {} of {} was rejected",
+                    outcome.edits.len(),
+                    edits.len()
+                ),
+            ))
+        };
+        match result {
+            Ok(diff) => {
+                outcome.diff = Some(diff);
+                outcome.committed = true;
+            }
+            Err(error) => {
+                if let Err(error) = self.discard() {
+                    log::warn!("failed to discard the candidate configuration: {error}");
+                }
+                outcome.error = Some(error);
+            }
+        }
+
+        if locked {
+            if let Err(error) = self.unlock() {
+                log::warn!("failed to unlock the configuration: {error}");
+                outcome.unlock_error = Some(DeviceError::classify(error));
+            }
+        }
+        outcome
+    }
+}
+
+/// Outcome of [`SupportsConfigSession::apply_configs`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchOutcome {
+    /// Result of loading each edit, up to the first rejected one.
+    pub edits: Vec<Result<(), DeviceError>>,
+    /// Diff of the committed candidate.
+    pub diff: Option<String>,
+    pub committed: bool,
+    /// Why the batch was not committed. Always `None` once committed.
+    pub error: Option<DeviceError>,
+    /// Failure to release the lock, whether the batch was committed or
+    /// not.
+    pub unlock_error: Option<DeviceError>,
+}
+
+/// Callback receiving the updates of a subscription.
+pub type SubscriptionCallback = Box<dyn FnMut(serde_json::Value) + Send>;
+
+/// Connections that stream telemetry updates, e.g. gNMI subscriptions.
+pub trait SupportsSubscribe: Connection {
+    fn subscribe(&mut self, path: &str, callback: SubscriptionCallback) -> Result<(), String>;
+}
+
+/// A capability a connection may implement, used with
+/// [`ConnectionManager::with_capability`].
+pub trait Capability {
+    /// Human readable name used in errors, e.g. `file transfer`.
+    const NAME: &'static str;
+
+    type Object: ?Sized;
+
+    fn get(connection: &mut dyn Connection) -> Option<&mut Self::Object>;
+}
+
+macro_rules! capability {
+    ($(#[$doc:meta])* $name:ident, $trait:ident, $method:ident, $label:literal) => {
+        $(#[$doc])*
+        pub struct $name;
+
+        impl Capability for $name {
+            const NAME: &'static str = $label;
+
+            type Object = dyn $trait;
+
+            fn get(connection: &mut dyn Connection) -> Option<&mut Self::Object> {
+                connection.$method()
+            }
+        }
+    };
+}
+
+capability!(
+    /// [`SupportsExec`]
+    Exec,
+    SupportsExec,
+    as_exec,
+    "command execution"
+);
+capability!(
+    /// [`SupportsFileTransfer`]
+    FileTransfer,
+    SupportsFileTransfer,
+    as_file_transfer,
+    "file transfer"
+);
+capability!(
+    /// [`SupportsConfigSession`]
+    ConfigSession,
+    SupportsConfigSession,
+    as_config_session,
+    "configuration sessions"
+);
+capability!(
+    /// [`SupportsSubscribe`]
+    Subscribe,
+    SupportsSubscribe,
+    as_subscribe,
+    "subscriptions"
+);
+
+/// Error returned by [`ConnectionManager::with_capability`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CapabilityError {
+    /// No connection is stored under the key.
+    NotConnected { plugin: String, host: String },
+    /// The connection does not implement the capability.
+    Unsupported {
+        plugin: String,
+        host: String,
+        capability: &'static str,
+    },
+    /// A thread panicked while holding the connection.
+    Poisoned { plugin: String, host: String },
+}
+
+impl fmt::Display for CapabilityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CapabilityError::NotConnected { plugin, host } => {
+                write!(f, "no `{plugin}` connection to host `{host}`")
+            }
+            CapabilityError::Unsupported {
+                plugin,
+                host,
+                capability,
+            } => write!(
+                f,
+                "plugin `{plugin}` doesn't support {capability} on host `{host}`"
+            ),
+            CapabilityError::Poisoned { plugin, host } => {
+                write!(f, "`{plugin}` connection to host `{host}` is poisoned")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CapabilityError {}
+
+impl ConnectionManager {
+    /// Locks the connection stored under `key` and runs `f` with its
+    /// capability `C`, e.g. `with_capability::<Exec, _>(&key, |c| c.exec("show version"))`.
+    ///
+    /// Tasks that know their connection type at compile time can bound on
+    /// the `Supports*` traits directly instead.
+    pub fn with_capability<C, R>(
+        &self,
+        key: &ConnectionKey,
+        f: impl FnOnce(&mut C::Object) -> R,
+    ) -> Result<R, CapabilityError>
+    where
+        C: Capability,
+    {
+        let plugin = || key.connection_type.clone();
+        let host = || key.hostname.clone();
+        let connection = self.get(key).ok_or_else(|| CapabilityError::NotConnected {
+            plugin: plugin(),
+            host: host(),
+        })?;
+        let mut connection = connection.lock().map_err(|_| CapabilityError::Poisoned {
+            plugin: plugin(),
+            host: host(),
+        })?;
+        let object = C::get(&mut *connection).ok_or_else(|| CapabilityError::Unsupported {
+            plugin: plugin(),
+            host: host(),
+            capability: C::NAME,
+        })?;
+        Ok(f(object))
+    }
+
+    /// Returns `true` if the connection stored under `key` implements `C`.
+    pub fn supports<C: Capability>(&self, key: &ConnectionKey) -> bool {
+        self.with_capability::<C, _>(key, |_| ()).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::ResolvedConnectionParams;
+
+    #[derive(Debug)]
+    struct ExecOnly;
+
+    impl Connection for ExecOnly {
+        fn is_alive(&self) -> bool {
+            true
+        }
+
+        fn open(&mut self, _params: &ResolvedConnectionParams) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn close(&mut self) -> ConnectionKey {
+            ConnectionKey::new("r1", "ssh")
+        }
+
+        fn as_exec(&mut self) -> Option<&mut (dyn SupportsExec + 'static)> {
+            Some(self)
+        }
+    }
+
+    impl SupportsExec for ExecOnly {
+        fn exec(&mut self, command: &str) -> Result<String, String> {
+            Ok(format!("output of {command}"))
+        }
+    }
+
+    #[test]
+    fn test_with_capability() {
+        let manager = ConnectionManager::default();
+        let key = ConnectionKey::new("r1", "ssh");
+        manager.get_or_create(key.clone(), || ExecOnly);
+
+        let output = manager.with_capability::<Exec, _>(&key, |conn| conn.exec("show version"));
+        assert_eq!(output, Ok(Ok("output of show version".to_string())));
+        assert!(manager.supports::<Exec>(&key));
+
+        let error = manager
+            .with_capability::<FileTransfer, _>(&key, |_| ())
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "plugin `ssh` doesn't support file transfer on host `r1`"
+        );
+
+        let missing = ConnectionKey::new("r2", "ssh");
+        assert!(matches!(
+            manager.with_capability::<Exec, _>(&missing, |_| ()),
+            Err(CapabilityError::NotConnected { .. })
+        ));
+    }
+
+    #[derive(Debug, Default)]
+    struct Session {
+        busy_attempts: u32,
+        fail_commit: bool,
+        fail_unlock: bool,
+        reject: Option<&'static str>,
+        calls: Vec<&'static str>,
+    }
+
+    impl Connection for Session {
+        fn is_alive(&self) -> bool {
+            true
+        }
+
+        fn open(&mut self, _params: &ResolvedConnectionParams) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn close(&mut self) -> ConnectionKey {
+            ConnectionKey::new("r1", "netconf")
+        }
+    }
+
+    impl SupportsConfigSession for Session {
+        fn load_config(&mut self, config: &str) -> Result<(), String> {
+            self.calls.push("load");
+            if self.reject == Some(config) {
+                return Err(format!("% Invalid input: {config}"));
+            }
+            Ok(())
+        }
+
+        fn diff(&mut self) -> Result<String, String> {
+            Ok("+hostname r1".to_string())
+        }
+
+        fn commit(&mut self) -> Result<(), String> {
+            self.calls.push("commit");
+            if self.fail_commit {
+                return Err("commit failed: invalid value".to_string());
+            }
+            Ok(())
+        }
+
+        fn discard(&mut self) -> Result<(), String> {
+            self.calls.push("discard");
+            Ok(())
+        }
+
+        fn supports_lock(&self) -> bool {
+            true
+        }
+
+        fn lock(&mut self) -> Result<(), String> {
+            self.calls.push("lock");
+            if self.busy_attempts > 0 {
+                self.busy_attempts -= 1;
+                return Err("<error-tag>lock-denied</error-tag>".to_string());
+            }
+            Ok(())
+        }
+
+        fn unlock(&mut self) -> Result<(), String> {
+            self.calls.push("unlock");
+            if self.fail_unlock {
+                return Err("session closed".to_string());
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_apply_config_locks_and_unlocks() {
+        let retry = RetryPolicy {
+            attempts: 3,
+            delay: 0.0,
+            max_delay: 0.0,
+        };
+        let mut session = Session {
+            busy_attempts: 2,
+            ..Session::default()
+        };
+        assert_eq!(
+            session.apply_config("hostname r1", &retry).unwrap(),
+            "+hostname r1"
+        );
+        assert_eq!(
+            session.calls,
+            ["lock", "lock", "lock", "load", "commit", "unlock"]
+        );
+
+        let mut session = Session {
+            fail_commit: true,
+            ..Session::default()
+        };
+        let error = session.apply_config("hostname r1", &retry).unwrap_err();
+        assert_eq!(error.kind, DeviceErrorKind::CommandRejected);
+        assert_eq!(
+            session.calls,
+            ["lock", "load", "commit", "discard", "unlock"]
+        );
+
+        let mut session = Session {
+            busy_attempts: 5,
+            ..Session::default()
+        };
+        let error = session.apply_config("hostname r1", &retry).unwrap_err();
+        assert_eq!(error.kind, DeviceErrorKind::ConfigLocked);
+        assert!(!session.calls.contains(&"load"));
+
+        // The commit is live even though the lock could not be released.
+        let mut session = Session {
+            fail_unlock: true,
+            ..Session::default()
+        };
+        assert_eq!(
+            session.apply_config("hostname r1", &retry),
+            Ok("+hostname r1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_apply_configs_commits_once() {
+        let retry = RetryPolicy::never();
+        let mut session = Session::default();
+        let outcome = session.apply_configs(&["interface Et1", "interface Et2"], &retry);
+        assert!(outcome.committed);
+        assert_eq!(outcome.edits, [Ok(()), Ok(())]);
+        assert_eq!(session.calls, ["lock", "load", "load", "commit", "unlock"]);
+
+        let mut session = Session {
+            reject: Some("bogus"),
+            ..Session::default()
+        };
+        let outcome = session.apply_configs(&["interface Et1", "bogus", "interface Et3"], &retry);
+        assert!(!outcome.committed);
+        assert_eq!(outcome.edits.len(), 2);
+        assert_eq!(
+            outcome.edits[1].as_ref().unwrap_err().kind,
+            DeviceErrorKind::CommandRejected
+        );
+        assert_eq!(outcome.error.unwrap().message, "edit 2 of 3 was rejected");
+        assert_eq!(session.calls, ["lock", "load", "load", "discard", "unlock"]);
+    }
+
+    #[test]
+    fn test_apply_configs_keeps_commit_when_unlock_fails() {
+        let mut session = Session {
+            fail_unlock: true,
+            ..Session::default()
+        };
+        let outcome = session.apply_configs(&["interface Et1"], &RetryPolicy::never());
+        assert!(outcome.committed);
+        assert_eq!(outcome.diff.as_deref(), Some("+hostname r1"));
+        assert_eq!(outcome.error, None);
+        assert_eq!(outcome.unlock_error.unwrap().message, "session closed");
+    }
+}
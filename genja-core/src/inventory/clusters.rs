@@ -0,0 +1,187 @@
+use super::{Host, Inventory};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Data key holding a host's cluster membership.
+const CLUSTER_KEY: &str = "cluster";
+
+/// Role of a host in an HA pair or cluster.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ClusterRole {
+    Standby,
+    /// A member without a fixed active/standby role, e.g. in an
+    /// active/active cluster.
+    #[default]
+    Member,
+    Active,
+}
+
+/// A host's membership in a cluster, read from
+/// `data.cluster: { name: <cluster>, role: active|standby|member }`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClusterMembership {
+    pub name: String,
+    #[serde(default)]
+    pub role: ClusterRole,
+}
+
+impl ClusterMembership {
+    pub fn is_active(&self) -> bool {
+        self.role == ClusterRole::Active
+    }
+}
+
+/// The members of a cluster, see [`Inventory::clusters`].
+#[derive(Debug, Clone)]
+pub struct Cluster<'a> {
+    pub name: String,
+    /// Members in natural order of their host names.
+    pub members: Vec<(&'a Host, ClusterRole)>,
+}
+
+impl<'a> Cluster<'a> {
+    /// The members with `role`.
+    pub fn with_role(&self, role: ClusterRole) -> impl Iterator<Item = &'a Host> + '_ {
+        self.members
+            .iter()
+            .filter(move |(_, member_role)| *member_role == role)
+            .map(|(host, _)| *host)
+    }
+
+    pub fn active(&self) -> Option<&'a Host> {
+        self.with_role(ClusterRole::Active).next()
+    }
+
+    pub fn standby(&self) -> Option<&'a Host> {
+        self.with_role(ClusterRole::Standby).next()
+    }
+}
+
+impl Host {
+    /// Returns the cluster this host is a member of, if any.
+    ///
+    /// An invalid `cluster` entry is logged and ignored.
+    pub fn cluster(&self) -> Option<ClusterMembership> {
+        let value = self.data.as_ref()?.get(CLUSTER_KEY)?;
+        match ClusterMembership::deserialize(value) {
+            Ok(membership) => Some(membership),
+            Err(error) => {
+                log::warn!("ignoring invalid cluster of host `{}`: {error}", self.name);
+                None
+            }
+        }
+    }
+}
+
+impl Inventory {
+    /// All clusters of the inventory by name.
+    pub fn clusters(&self) -> BTreeMap<String, Cluster<'_>> {
+        let mut clusters: BTreeMap<String, Cluster<'_>> = BTreeMap::new();
+        for host in self.hosts.values() {
+            let Some(membership) = host.cluster() else {
+                continue;
+            };
+            clusters
+                .entry(membership.name.clone())
+                .or_insert_with(|| Cluster {
+                    name: membership.name,
+                    members: Vec::new(),
+                })
+                .members
+                .push((host, membership.role));
+        }
+        clusters
+    }
+
+    /// Splits `hosts` into waves so that no two members of a cluster are in
+    /// the same wave. Running the waves one after another never acts on
+    /// both members of a pair at once.
+    ///
+    /// Standby members come before members without a role, and active
+    /// members last, so the active node is only touched once its peers are
+    /// done. Hosts outside any cluster are in the first wave. Names missing
+    /// from the inventory are skipped.
+    pub fn cluster_waves<'a>(&self, hosts: impl IntoIterator<Item = &'a str>) -> Vec<Vec<&'a str>> {
+        let mut by_cluster: BTreeMap<String, Vec<(ClusterRole, &'a str)>> = BTreeMap::new();
+        let mut waves: Vec<Vec<&'a str>> = vec![Vec::new()];
+        for name in hosts {
+            let Some(host) = self.hosts.get(name) else {
+                continue;
+            };
+            match host.cluster() {
+                Some(membership) => by_cluster
+                    .entry(membership.name)
+                    .or_default()
+                    .push((membership.role, name)),
+                None => waves[0].push(name),
+            }
+        }
+        for mut members in by_cluster.into_values() {
+            members.sort_by_key(|(role, _)| *role);
+            for (index, (_, name)) in members.into_iter().enumerate() {
+                if waves.len() <= index {
+                    waves.push(Vec::new());
+                }
+                waves[index].push(name);
+            }
+        }
+        waves.retain(|wave| !wave.is_empty());
+        waves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn inventory() -> Inventory {
+        let member = |name: &str, cluster: &str, role: &str| {
+            (
+                name.to_string(),
+                json!({ "name": name, "data": { "cluster": { "name": cluster, "role": role } } }),
+            )
+        };
+        let mut hosts: serde_json::Map<String, serde_json::Value> = [
+            member("fw1", "fw-pair", "active"),
+            member("fw2", "fw-pair", "standby"),
+            member("lb1", "lb-pair", "standby"),
+            member("lb2", "lb-pair", "active"),
+        ]
+        .into_iter()
+        .collect();
+        hosts.insert("r1".to_string(), json!({ "name": "r1" }));
+        serde_json::from_value(json!({ "hosts": hosts })).unwrap()
+    }
+
+    #[test]
+    fn test_clusters() {
+        let inventory = inventory();
+        let clusters = inventory.clusters();
+        assert_eq!(clusters.len(), 2);
+        let pair = &clusters["fw-pair"];
+        assert_eq!(pair.active().unwrap().name, "fw1");
+        assert_eq!(pair.standby().unwrap().name, "fw2");
+        assert!(inventory
+            .hosts
+            .get("fw1")
+            .and_then(Host::cluster)
+            .unwrap()
+            .is_active());
+        assert_eq!(inventory.hosts.get("r1").unwrap().cluster(), None);
+    }
+
+    #[test]
+    fn test_cluster_waves_never_pair_members() {
+        let inventory = inventory();
+        let waves = inventory.cluster_waves(["fw1", "fw2", "lb1", "lb2", "r1", "missing"]);
+        assert_eq!(waves, [vec!["r1", "fw2", "lb1"], vec!["fw1", "lb2"]]);
+
+        let waves = inventory.cluster_waves(["fw1", "lb2"]);
+        assert_eq!(waves, [vec!["fw1", "lb2"]]);
+    }
+}
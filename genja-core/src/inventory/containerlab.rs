@@ -0,0 +1,351 @@
+use super::{
+    BaseBuilderHost, ConnectionOptions, Data, Group, Groups, Host, Hosts, Inventory, ParentGroups,
+};
+use crate::{CustomTreeMap, NatString};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+/// Options for [`Inventory::from_containerlab`].
+#[derive(Debug, Clone, Default)]
+pub struct ContainerlabOptions {
+    /// Adds `container` connection options to every host so commands run
+    /// through `docker exec` on the lab node, see `ContainerConnection`.
+    pub lab_mode: bool,
+}
+
+impl ContainerlabOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn lab_mode(mut self, lab_mode: bool) -> Self {
+        self.lab_mode = lab_mode;
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Topology {
+    name: String,
+    prefix: Option<String>,
+    #[serde(default)]
+    topology: TopologyBody,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TopologyBody {
+    #[serde(default)]
+    defaults: NodeSpec,
+    #[serde(default)]
+    kinds: BTreeMap<String, NodeSpec>,
+    #[serde(default)]
+    nodes: BTreeMap<String, Option<NodeSpec>>,
+    #[serde(default)]
+    links: Vec<Link>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct NodeSpec {
+    kind: Option<String>,
+    image: Option<String>,
+    group: Option<String>,
+    mgmt_ipv4: Option<String>,
+    mgmt_ipv6: Option<String>,
+    #[serde(default)]
+    labels: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Link {
+    #[serde(default)]
+    endpoints: Vec<String>,
+}
+
+/// Maps containerlab kinds to the platform names used by connection plugins.
+fn platform_for_kind(kind: &str) -> &str {
+    match kind {
+        "ceos" | "arista_ceos" | "vr-veos" | "arista_veos" => "eos",
+        "srl" | "nokia_srlinux" => "srlinux",
+        "vr-sros" | "nokia_sros" => "sros",
+        "crpd"
+        | "juniper_crpd"
+        | "vr-vmx"
+        | "juniper_vmx"
+        | "juniper_vjunosrouter"
+        | "juniper_vjunosswitch"
+        | "vr-vqfx"
+        | "juniper_vqfx" => "junos",
+        "xrd" | "cisco_xrd" | "vr-xrv9k" | "cisco_xrv9k" => "iosxr",
+        "vr-csr" | "cisco_csr1000v" | "cisco_c8000v" | "cisco_iol" => "ios",
+        "vr-n9kv" | "cisco_n9kv" => "nxos",
+        other => other,
+    }
+}
+
+/// Shell used by `docker exec` to reach the node's CLI, where it differs
+/// from `sh -c`.
+fn cli_shell(platform: &str) -> Option<Vec<&'static str>> {
+    match platform {
+        "eos" => Some(vec!["Cli", "-p", "15", "-c"]),
+        "srlinux" => Some(vec!["sr_cli", "--"]),
+        _ => None,
+    }
+}
+
+impl Topology {
+    fn container_name(&self, node: &str) -> String {
+        match self.prefix.as_deref() {
+            Some("") => node.to_string(),
+            Some("__lab-name") => format!("{}-{node}", self.name),
+            Some(prefix) => format!("{prefix}-{}-{node}", self.name),
+            None => format!("clab-{}-{node}", self.name),
+        }
+    }
+
+    /// Node settings with the kind and default settings filled in.
+    fn node(&self, name: &str) -> NodeSpec {
+        let body = &self.topology;
+        let node = body.nodes.get(name).cloned().flatten().unwrap_or_default();
+        let kind = node.kind.clone().or_else(|| body.defaults.kind.clone());
+        let kind_spec = kind.as_ref().and_then(|kind| body.kinds.get(kind));
+        let inherit = |field: fn(&NodeSpec) -> Option<String>| {
+            field(&node)
+                .or_else(|| kind_spec.and_then(field))
+                .or_else(|| field(&body.defaults))
+        };
+
+        let mut labels = body.defaults.labels.clone();
+        labels.extend(
+            kind_spec
+                .map(|spec| spec.labels.clone())
+                .unwrap_or_default(),
+        );
+        labels.extend(node.labels.clone());
+
+        NodeSpec {
+            kind,
+            image: inherit(|spec| spec.image.clone()),
+            group: inherit(|spec| spec.group.clone()),
+            mgmt_ipv4: node.mgmt_ipv4,
+            mgmt_ipv6: node.mgmt_ipv6,
+            labels,
+        }
+    }
+
+    /// `node -> [{ host, port, local_port }]` built from the link endpoints.
+    fn neighbors(&self) -> BTreeMap<String, Vec<Value>> {
+        let mut neighbors: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+        for link in &self.topology.links {
+            let [a, b] = link.endpoints.as_slice() else {
+                continue;
+            };
+            let split = |endpoint: &str| -> (String, String) {
+                match endpoint.split_once(':') {
+                    Some((node, port)) => (node.to_string(), port.to_string()),
+                    None => (endpoint.to_string(), String::new()),
+                }
+            };
+            let (a_node, a_port) = split(a);
+            let (b_node, b_port) = split(b);
+            neighbors.entry(a_node.clone()).or_default().push(json!({
+                "host": b_node,
+                "port": b_port,
+                "local_port": a_port,
+            }));
+            neighbors.entry(b_node).or_default().push(json!({
+                "host": a_node,
+                "port": a_port,
+                "local_port": b_port,
+            }));
+        }
+        neighbors
+    }
+}
+
+impl Inventory {
+    /// Builds an inventory from a containerlab topology file.
+    ///
+    /// * Each node becomes a host named after the node, with its
+    ///   `mgmt-ipv4` (or `mgmt-ipv6`) address as hostname, falling back to
+    ///   the container name.
+    /// * The node kind is mapped to a platform (`ceos` -> `eos`, `srl` ->
+    ///   `srlinux`, ...) and becomes a group carrying that platform. The
+    ///   node's `group` is added as a second group.
+    /// * `kind`, `image`, `container` and `labels` are stored in the host
+    ///   data, and the topology links in `neighbors`, which
+    ///   [`Diagram::Adjacency`](super::Diagram::Adjacency) renders.
+    ///
+    /// Kind and default settings of the topology are inherited by nodes.
+    pub fn from_containerlab(
+        topology: &str,
+        options: &ContainerlabOptions,
+    ) -> Result<Inventory, serde_yaml::Error> {
+        let topology: Topology = serde_yaml::from_str(topology)?;
+        let mut neighbors = topology.neighbors();
+        let mut hosts = Hosts::new();
+        let mut groups = CustomTreeMap::new();
+
+        for name in topology.topology.nodes.keys() {
+            let node = topology.node(name);
+            let container = topology.container_name(name);
+            let kind = node.kind.clone().unwrap_or_else(|| "linux".to_string());
+            let platform = platform_for_kind(&kind).to_string();
+
+            let mut host_groups = ParentGroups::new();
+            host_groups.push(kind.clone());
+            if let Some(group) = &node.group {
+                host_groups.push(group.clone());
+                groups
+                    .entry(NatString::new(group.clone()))
+                    .or_insert_with(Group::new);
+            }
+            groups.insert(
+                kind.as_str(),
+                Group {
+                    platform: Some(platform.clone()),
+                    ..Group::new()
+                },
+            );
+
+            let mut data = Map::new();
+            data.insert("kind".to_string(), Value::from(kind.as_str()));
+            data.insert("container".to_string(), Value::from(container.as_str()));
+            if let Some(image) = &node.image {
+                data.insert("image".to_string(), Value::from(image.as_str()));
+            }
+            if !node.labels.is_empty() {
+                data.insert("labels".to_string(), json!(node.labels));
+            }
+            if let Some(links) = neighbors.remove(name) {
+                data.insert("neighbors".to_string(), Value::Array(links));
+            }
+
+            let hostname = node
+                .mgmt_ipv4
+                .or(node.mgmt_ipv6)
+                .unwrap_or_else(|| container.clone());
+            let mut builder = Host::builder(name)
+                .hostname(&hostname)
+                .platform(&platform)
+                .groups(host_groups)
+                .data(Data::new(Value::Object(data)));
+            if options.lab_mode {
+                let mut extras = json!({ "container": container });
+                if let Some(shell) = cli_shell(&platform) {
+                    extras["shell"] = json!(shell);
+                }
+                let connection: ConnectionOptions =
+                    serde_json::from_value(json!({ "extras": extras }))
+                        .expect("container options are valid connection options");
+                builder = builder.connection_options("container".to_string(), connection);
+            }
+            hosts.add_host(builder.build());
+        }
+
+        Ok(Inventory::builder()
+            .hosts(hosts)
+            .groups(Groups(groups))
+            .build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::{Diagram, DiagramOptions};
+
+    const TOPOLOGY: &str = r#"
+name: dc1
+topology:
+  defaults:
+    labels:
+      site: ams
+  kinds:
+    ceos:
+      image: ceos:4.32
+    linux:
+      image: alpine:3
+  nodes:
+    spine1:
+      kind: ceos
+      mgmt-ipv4: 172.20.20.2
+      group: spines
+    leaf1:
+      kind: srl
+      labels:
+        rack: r1
+    client1:
+      kind: linux
+  links:
+    - endpoints: ["spine1:eth1", "leaf1:e1-1"]
+    - endpoints: ["leaf1:e1-2", "client1:eth1"]
+"#;
+
+    #[test]
+    fn test_from_containerlab() {
+        let inventory =
+            Inventory::from_containerlab(TOPOLOGY, &ContainerlabOptions::new()).unwrap();
+        assert_eq!(inventory.hosts.len(), 3);
+
+        let spine = inventory.hosts.get("spine1").unwrap();
+        assert_eq!(spine.hostname.as_deref(), Some("172.20.20.2"));
+        assert_eq!(spine.platform.as_deref(), Some("eos"));
+        assert_eq!(
+            spine.groups.as_ref().unwrap().as_slice(),
+            ["ceos", "spines"]
+        );
+        assert!(spine.connection_options.is_none());
+        let data = spine.data.as_ref().unwrap();
+        assert_eq!(data["image"], "ceos:4.32");
+        assert_eq!(data["labels"], json!({ "site": "ams" }));
+        assert_eq!(
+            data["neighbors"],
+            json!([{ "host": "leaf1", "port": "e1-1", "local_port": "eth1" }])
+        );
+
+        let leaf = inventory.hosts.get("leaf1").unwrap();
+        assert_eq!(leaf.hostname.as_deref(), Some("clab-dc1-leaf1"));
+        assert_eq!(leaf.platform.as_deref(), Some("srlinux"));
+        assert_eq!(
+            leaf.data.as_ref().unwrap()["labels"],
+            json!({ "site": "ams", "rack": "r1" })
+        );
+
+        let groups = inventory.groups.as_ref().unwrap();
+        assert_eq!(
+            groups.get("srl").unwrap().platform.as_deref(),
+            Some("srlinux")
+        );
+        assert!(groups.get("spines").is_some());
+
+        let mermaid = inventory.to_mermaid(Diagram::Adjacency, &DiagramOptions::new());
+        assert!(mermaid.contains("h_leaf1 --- h_spine1"));
+        assert!(mermaid.contains("h_client1 --- h_leaf1"));
+    }
+
+    #[test]
+    fn test_lab_mode_uses_container_plugin() {
+        let options = ContainerlabOptions::new().lab_mode(true);
+        let inventory = Inventory::from_containerlab(TOPOLOGY, &options).unwrap();
+
+        let spine = inventory.hosts.get("spine1").unwrap();
+        let container = spine
+            .connection_options
+            .as_ref()
+            .and_then(|options| options.get("container"))
+            .unwrap();
+        assert_eq!(
+            **container.extras.as_ref().unwrap(),
+            json!({ "container": "clab-dc1-spine1", "shell": ["Cli", "-p", "15", "-c"] })
+        );
+
+        let client = inventory.hosts.get("client1").unwrap();
+        let options = client.connection_options.as_ref().unwrap();
+        assert_eq!(
+            **options.get("container").unwrap().extras.as_ref().unwrap(),
+            json!({ "container": "clab-dc1-client1" })
+        );
+    }
+}
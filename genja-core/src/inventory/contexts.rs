@@ -0,0 +1,259 @@
+use super::{
+    ConnectionKey, Host, Inventory, ParamProvenance, ParamSource, ResolvedConnectionParams,
+};
+use serde::{Deserialize, Serialize};
+
+/// Data key marking a host as a context of another host.
+const CONTEXT_KEY: &str = "context";
+
+/// A virtual context of a physical device: a firewall context, a Junos
+/// logical system, a Nexus VDC, ...
+///
+/// Contexts are hosts whose data carries
+/// `context: { parent: <host>, name: <context> }`. They are reached through
+/// their parent's transport, see [`Inventory::connection_key`], and tasks
+/// switch to `name` before running against them. A context with its own
+/// `connection_options` for a plugin gets a connection of its own instead.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceContext {
+    /// Name of the host of the physical device.
+    pub parent: String,
+    /// Name of the context on the device. Defaults to the host name.
+    pub name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawContext {
+    parent: String,
+    name: Option<String>,
+}
+
+impl Host {
+    /// Returns the device context this host represents, if any.
+    ///
+    /// An invalid `context` entry is logged and ignored.
+    pub fn device_context(&self) -> Option<DeviceContext> {
+        let value = self.data.as_ref()?.get(CONTEXT_KEY)?;
+        match RawContext::deserialize(value) {
+            Ok(raw) => Some(DeviceContext {
+                parent: raw.parent,
+                name: raw.name.unwrap_or_else(|| self.name.clone()),
+            }),
+            Err(error) => {
+                log::warn!("ignoring invalid context of host `{}`: {error}", self.name);
+                None
+            }
+        }
+    }
+}
+
+impl Inventory {
+    /// Returns the parent of a context host.
+    ///
+    /// Contexts are one level deep: `None` is returned when the parent is
+    /// missing or is itself a context.
+    pub fn context_parent(&self, host: &Host) -> Option<&Host> {
+        let context = host.device_context()?;
+        let parent = self.hosts.get(&context.parent)?;
+        if parent.device_context().is_some() {
+            log::warn!(
+                "host `{}` is a context of `{}`, which is itself a context",
+                host.name,
+                parent.name
+            );
+            return None;
+        }
+        Some(parent)
+    }
+
+    /// The context hosts of `parent`, in natural order.
+    pub fn device_contexts(&self, parent: &str) -> Vec<&Host> {
+        self.hosts
+            .values()
+            .filter(|host| {
+                host.device_context()
+                    .is_some_and(|context| context.parent == parent)
+            })
+            .collect()
+    }
+
+    /// The key under which the `plugin` connection to `host` is stored.
+    ///
+    /// Contexts share the connection of their parent, so opening a context
+    /// after its parent reuses the parent's transport. A context overriding
+    /// parameters in its `connection_options.<plugin>`, e.g. its own
+    /// credentials, is keyed by its own name: the parent's transport was
+    /// opened with other parameters.
+    pub fn connection_key(&self, host: &Host, plugin: &str) -> ConnectionKey {
+        let name = match self.context_parent(host) {
+            Some(parent) if !overrides_connection(host, plugin) => parent.name.as_str(),
+            _ => host.name.as_str(),
+        };
+        ConnectionKey::new(name, plugin)
+    }
+}
+
+/// Returns `true` if `host` sets any field in `connection_options.<plugin>`.
+fn overrides_connection(host: &Host, plugin: &str) -> bool {
+    let Some(options) = host
+        .connection_options
+        .as_ref()
+        .and_then(|options| options.get(plugin))
+    else {
+        return false;
+    };
+    options.hostname.is_some()
+        || options.port.is_some()
+        || options.username.is_some()
+        || options.password.is_some()
+        || options.platform.is_some()
+        || options.extras.is_some()
+        || options.credentials.is_some()
+        || options.tls.is_some()
+        || options.proxy.is_some()
+}
+
+/// Resolves the parameters of a context host: the parent's parameters, with
+/// the fields set in the context's own `connection_options.<plugin>` on top.
+pub(super) fn explain_context_params(
+    inventory: &Inventory,
+    host: &Host,
+    plugin: &str,
+) -> Option<(ResolvedConnectionParams, ParamProvenance)> {
+    let parent = inventory.context_parent(host)?;
+    let (mut params, provenance) = inventory.explain_connection_params(parent, plugin);
+    // Even a hostname falling back to the host name is the parent's name.
+    let from_parent = |_: ParamSource| ParamSource::Parent(parent.name.clone());
+    let mut provenance = ParamProvenance {
+        hostname: from_parent(provenance.hostname),
+        port: provenance.port.map(from_parent),
+        username: provenance.username.map(from_parent),
+        password: provenance.password.map(from_parent),
+        platform: provenance.platform.map(from_parent),
+        extras: provenance.extras.map(from_parent),
+        credentials: provenance.credentials.map(from_parent),
+        tls: provenance.tls.map(from_parent),
+        proxy: provenance.proxy.map(from_parent),
+    };
+
+    let Some(options) = host
+        .connection_options
+        .as_ref()
+        .and_then(|options| options.get(plugin))
+    else {
+        return Some((params, provenance));
+    };
+    macro_rules! overlay {
+        ($field:ident) => {
+            if let Some(value) = &options.$field {
+                params.$field = value.clone().into();
+                provenance.$field = ParamSource::HostConnectionOptions.into();
+            }
+        };
+    }
+    overlay!(hostname);
+    overlay!(port);
+    overlay!(username);
+    overlay!(password);
+    overlay!(platform);
+    overlay!(extras);
+    overlay!(credentials);
+    overlay!(tls);
+    overlay!(proxy);
+    Some((params, provenance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connections::LocalConnection;
+    use serde_json::json;
+
+    fn inventory() -> Inventory {
+        serde_json::from_value(json!({
+            "hosts": {
+                "fw1": {
+                    "name": "fw1",
+                    "hostname": "10.0.0.1",
+                    "username": "admin",
+                    "platform": "asa"
+                },
+                "fw1-dmz": {
+                    "name": "fw1-dmz",
+                    "data": { "context": { "parent": "fw1", "name": "dmz" } },
+                    "connection_options": { "ssh": { "username": "dmz-admin" } }
+                },
+                "fw1-inside": {
+                    "name": "fw1-inside",
+                    "data": { "context": { "parent": "fw1" } }
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_contexts_use_parent_params() {
+        let inventory = inventory();
+        let dmz = inventory.hosts.get("fw1-dmz").unwrap();
+        assert_eq!(
+            dmz.device_context(),
+            Some(DeviceContext {
+                parent: "fw1".to_string(),
+                name: "dmz".to_string()
+            })
+        );
+        let names: Vec<&str> = inventory
+            .device_contexts("fw1")
+            .iter()
+            .map(|host| host.name.as_str())
+            .collect();
+        assert_eq!(names, ["fw1-dmz", "fw1-inside"]);
+
+        let (params, provenance) = inventory.explain_connection_params(dmz, "ssh");
+        assert_eq!(params.to_string(), "10.0.0.1 (asa) user=dmz-admin");
+        assert_eq!(provenance.hostname, ParamSource::Parent("fw1".to_string()));
+        assert_eq!(
+            provenance.username,
+            Some(ParamSource::HostConnectionOptions)
+        );
+        assert_eq!(
+            inventory.connection_key(dmz, "ssh"),
+            ConnectionKey::new("fw1-dmz", "ssh")
+        );
+        assert_eq!(
+            inventory.connection_key(dmz, "netconf"),
+            ConnectionKey::new("fw1", "netconf")
+        );
+    }
+
+    #[test]
+    fn test_context_hostname_provenance() {
+        let inventory: Inventory = serde_json::from_value(json!({
+            "hosts": {
+                "fw1": { "name": "fw1" },
+                "fw1-dmz": { "name": "fw1-dmz", "data": { "context": { "parent": "fw1" } } }
+            }
+        }))
+        .unwrap();
+        let dmz = inventory.hosts.get("fw1-dmz").unwrap();
+        let (params, provenance) = inventory.explain_connection_params(dmz, "ssh");
+        assert_eq!(params.hostname, "fw1");
+        assert_eq!(provenance.hostname, ParamSource::Parent("fw1".to_string()));
+    }
+
+    #[test]
+    fn test_contexts_share_the_parent_connection() {
+        let inventory = inventory();
+        for name in ["fw1", "fw1-dmz", "fw1-inside"] {
+            let host = inventory.hosts.get(name).unwrap();
+            let key = inventory.connection_key(host, "ssh");
+            inventory
+                .connections
+                .get_or_create(key, LocalConnection::new);
+        }
+        // fw1-dmz has its own ssh credentials, so its own connection.
+        assert_eq!(inventory.connections.len(), 2);
+    }
+}
@@ -0,0 +1,238 @@
+use super::{ConnectionKey, ConnectionManager, ResolvedConnectionParams};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+
+/// A username/password pair tried when opening a connection.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct Credential {
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+impl Credential {
+    pub fn new(username: &str, password: &str) -> Self {
+        Credential {
+            username: Some(username.to_string()),
+            password: Some(password.to_string()),
+        }
+    }
+}
+
+/// The password is never printed.
+impl fmt::Debug for Credential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credential")
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+/// Error returned by a single authentication attempt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CredentialError {
+    /// The device rejected the credential; the next candidate is tried.
+    Rejected(String),
+    /// Any other failure, e.g. the host is unreachable. No further
+    /// candidates are tried.
+    Failed(String),
+}
+
+impl fmt::Display for CredentialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CredentialError::Rejected(message) => write!(f, "credential rejected: {message}"),
+            CredentialError::Failed(message) => write!(f, "connection failed: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for CredentialError {}
+
+/// What the manager remembers about the candidates of one connection.
+#[derive(Debug, Default)]
+pub(crate) struct CredentialState {
+    accepted: Option<Credential>,
+    rejected: HashSet<Credential>,
+}
+
+impl ResolvedConnectionParams {
+    /// Returns the credentials to try in order: the resolved
+    /// `username`/`password` first, followed by the `credentials` list.
+    /// Duplicates and empty entries are skipped.
+    pub fn credential_candidates(&self) -> Vec<Credential> {
+        let primary = Credential {
+            username: self.username.clone(),
+            password: self.password.clone(),
+        };
+        let mut candidates: Vec<Credential> = Vec::new();
+        for credential in std::iter::once(primary).chain(self.credentials.iter().cloned()) {
+            let empty = credential.username.is_none() && credential.password.is_none();
+            if !empty && !candidates.contains(&credential) {
+                candidates.push(credential);
+            }
+        }
+        candidates
+    }
+}
+
+impl ConnectionManager {
+    /// Runs `attempt` with the credential candidates of `params` until one is
+    /// accepted, and returns the accepted credential.
+    ///
+    /// A credential accepted earlier for the same `key` is tried first and
+    /// credentials rejected earlier are skipped. At most `max_attempts`
+    /// attempts are made so that devices with lockout policies are not
+    /// locked out by a long candidate list. A `CredentialError::Failed` stops
+    /// the search immediately.
+    pub fn try_credentials<F>(
+        &self,
+        key: &ConnectionKey,
+        params: &ResolvedConnectionParams,
+        max_attempts: usize,
+        mut attempt: F,
+    ) -> Result<Credential, CredentialError>
+    where
+        F: FnMut(&Credential) -> Result<(), CredentialError>,
+    {
+        let mut candidates = params.credential_candidates();
+        {
+            let state = self.credential_state.entry(key.clone()).or_default();
+            candidates.retain(|credential| !state.rejected.contains(credential));
+            if let Some(accepted) = &state.accepted {
+                if let Some(index) = candidates.iter().position(|c| c == accepted) {
+                    let accepted = candidates.remove(index);
+                    candidates.insert(0, accepted);
+                }
+            }
+        }
+
+        let mut last_error =
+            CredentialError::Rejected(format!("no untried credentials left for {}", key.hostname));
+        for credential in candidates.into_iter().take(max_attempts) {
+            // The state is not locked while `attempt` runs, which may block.
+            let result = attempt(&credential);
+            let mut state = self.credential_state.entry(key.clone()).or_default();
+            match result {
+                Ok(()) => {
+                    state.accepted = Some(credential.clone());
+                    return Ok(credential);
+                }
+                Err(CredentialError::Rejected(message)) => {
+                    log::debug!(
+                        "{}: credential for {:?} rejected",
+                        key.hostname,
+                        credential.username
+                    );
+                    if state.accepted.as_ref() == Some(&credential) {
+                        state.accepted = None;
+                    }
+                    state.rejected.insert(credential);
+                    last_error = CredentialError::Rejected(message);
+                }
+                Err(error @ CredentialError::Failed(_)) => return Err(error),
+            }
+        }
+        Err(last_error)
+    }
+
+    /// Forgets accepted and rejected credentials for `key`, e.g. after the
+    /// device's passwords were rotated.
+    pub fn reset_credentials(&self, key: &ConnectionKey) {
+        self.credential_state.remove(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> ResolvedConnectionParams {
+        ResolvedConnectionParams {
+            hostname: "r1".to_string(),
+            port: None,
+            username: Some("admin".to_string()),
+            password: Some("old".to_string()),
+            platform: None,
+            extras: None,
+            credentials: vec![
+                Credential::new("admin", "old"),
+                Credential::new("admin", "new"),
+                Credential::new("backup", "secret"),
+            ],
+            tls: None,
+            proxy: None,
+        }
+    }
+
+    #[test]
+    fn test_candidates_are_deduplicated() {
+        let candidates = params().credential_candidates();
+        assert_eq!(candidates.len(), 3);
+        assert_eq!(candidates[0], Credential::new("admin", "old"));
+    }
+
+    #[test]
+    fn test_accepted_credential_is_remembered() {
+        let manager = ConnectionManager::default();
+        let key = ConnectionKey::new("r1", "ssh");
+        let mut tried = Vec::new();
+        let accepted = manager
+            .try_credentials(&key, &params(), 5, |credential| {
+                tried.push(credential.password.clone().unwrap());
+                match credential.password.as_deref() {
+                    Some("new") => Ok(()),
+                    _ => Err(CredentialError::Rejected("auth failed".to_string())),
+                }
+            })
+            .unwrap();
+        assert_eq!(accepted, Credential::new("admin", "new"));
+        assert_eq!(tried, ["old", "new"]);
+
+        tried.clear();
+        manager
+            .try_credentials(&key, &params(), 5, |credential| {
+                tried.push(credential.password.clone().unwrap());
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(tried, ["new"]);
+    }
+
+    #[test]
+    fn test_attempts_are_limited() {
+        let manager = ConnectionManager::default();
+        let key = ConnectionKey::new("r1", "ssh");
+        let mut attempts = 0;
+        let result = manager.try_credentials(&key, &params(), 2, |_| {
+            attempts += 1;
+            Err(CredentialError::Rejected("auth failed".to_string()))
+        });
+        assert_eq!(attempts, 2);
+        assert!(matches!(result, Err(CredentialError::Rejected(_))));
+
+        // Only the candidate that was never tried is left.
+        let accepted = manager.try_credentials(&key, &params(), 2, |_| Ok(()));
+        assert_eq!(accepted.unwrap(), Credential::new("backup", "secret"));
+    }
+
+    #[test]
+    fn test_failure_stops_the_search() {
+        let manager = ConnectionManager::default();
+        let key = ConnectionKey::new("r1", "ssh");
+        let mut attempts = 0;
+        let result = manager.try_credentials(&key, &params(), 5, |_| {
+            attempts += 1;
+            Err(CredentialError::Failed("connection refused".to_string()))
+        });
+        assert_eq!(attempts, 1);
+        assert_eq!(
+            result,
+            Err(CredentialError::Failed("connection refused".to_string()))
+        );
+    }
+}
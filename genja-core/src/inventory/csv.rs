@@ -0,0 +1,295 @@
+use super::{Data, Host, Inventory, InventoryError, LoadStage, ParentGroups};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::Path;
+
+/// Column mapping of [`Inventory::from_csv`].
+///
+/// Each field names the header of the column holding that host field, so
+/// exports with non-standard headers can be read, e.g. a CMDB export with
+/// `Device` and `Mgmt IP` columns:
+///
+/// ```
+/// # use genja_core::inventory::{CsvOptions, Inventory};
+/// let options = CsvOptions {
+///     name: "Device".to_string(),
+///     hostname: "Mgmt IP".to_string(),
+///     ..CsvOptions::default()
+/// };
+/// let csv = "Device,Mgmt IP,site\nr1,10.0.0.1,ams\n";
+///
+/// let inventory = Inventory::from_csv_str(csv, &options).unwrap();
+/// assert_eq!(inventory.hosts["r1"].hostname.as_deref(), Some("10.0.0.1"));
+/// assert_eq!(inventory.hosts["r1"].data.as_ref().unwrap()["site"], "ams");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CsvOptions {
+    pub name: String,
+    pub hostname: String,
+    pub port: String,
+    pub username: String,
+    pub platform: String,
+    /// Column listing the host's groups, separated by `group_separator`.
+    pub groups: String,
+    pub group_separator: char,
+    /// Columns copied into the host's data. `None` copies every column not
+    /// mapped to a host field.
+    pub data: Option<Vec<String>>,
+    pub delimiter: char,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            name: "name".to_string(),
+            hostname: "hostname".to_string(),
+            port: "port".to_string(),
+            username: "username".to_string(),
+            platform: "platform".to_string(),
+            groups: "groups".to_string(),
+            group_separator: ';',
+            data: None,
+            delimiter: ',',
+        }
+    }
+}
+
+fn csv_error(source: &str, message: impl std::fmt::Display) -> InventoryError {
+    InventoryError::Load {
+        stage: LoadStage::Parse,
+        message: format!("`{source}`: {message}"),
+    }
+}
+
+/// Splits CSV text into records of fields, with quoted fields as in
+/// RFC 4180. Blank lines are skipped. Records are numbered by their first
+/// line.
+fn parse_records(text: &str, delimiter: char) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut start = 1;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    quoted = false;
+                }
+            }
+            '"' if field.is_empty() => quoted = true,
+            '\n' if quoted => {
+                line += 1;
+                field.push(c);
+            }
+            c if c == delimiter && !quoted => record.push(std::mem::take(&mut field)),
+            '\r' if !quoted && chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|field| !field.is_empty()) {
+                    records.push((start, std::mem::take(&mut record)));
+                } else {
+                    record.clear();
+                }
+                line += 1;
+                start = line;
+            }
+            c => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(format!("line {start}: unterminated quoted field"));
+    }
+    record.push(field);
+    if record.iter().any(|field| !field.is_empty()) {
+        records.push((start, record));
+    }
+    Ok(records)
+}
+
+impl Inventory {
+    /// Reads hosts from a CSV file with a header row, see
+    /// [`Inventory::from_csv_str`].
+    pub fn from_csv(
+        path: impl AsRef<Path>,
+        options: &CsvOptions,
+    ) -> Result<Inventory, InventoryError> {
+        let path = path.as_ref();
+        let source = path.display().to_string();
+        let text = fs::read_to_string(path).map_err(|error| csv_error(&source, error))?;
+        Self::csv_inventory(&source, &text, options)
+    }
+
+    /// Reads hosts from CSV text with a header row, mapping columns to host
+    /// fields with `options`.
+    ///
+    /// Only the name column is required. Empty cells leave the field unset
+    /// and data values are kept as strings.
+    pub fn from_csv_str(text: &str, options: &CsvOptions) -> Result<Inventory, InventoryError> {
+        Self::csv_inventory("csv", text, options)
+    }
+
+    fn csv_inventory(
+        source: &str,
+        text: &str,
+        options: &CsvOptions,
+    ) -> Result<Inventory, InventoryError> {
+        let mut records = parse_records(text.trim_start_matches('\u{feff}'), options.delimiter)
+            .map_err(|error| csv_error(source, error))?
+            .into_iter();
+        let Some((_, header)) = records.next() else {
+            return Err(csv_error(source, "missing header row"));
+        };
+        let header: Vec<&str> = header.iter().map(|column| column.trim()).collect();
+        let column = |name: &str| header.iter().position(|column| *column == name);
+        let name_column = column(&options.name)
+            .ok_or_else(|| csv_error(source, format!("missing `{}` column", options.name)))?;
+
+        let mapped = [
+            &options.name,
+            &options.hostname,
+            &options.port,
+            &options.username,
+            &options.platform,
+            &options.groups,
+        ];
+        let data_columns: Vec<(usize, &str)> = match &options.data {
+            Some(columns) => columns
+                .iter()
+                .map(|name| {
+                    column(name)
+                        .map(|index| (index, name.as_str()))
+                        .ok_or_else(|| csv_error(source, format!("missing `{name}` column")))
+                })
+                .collect::<Result<_, _>>()?,
+            None => header
+                .iter()
+                .enumerate()
+                .filter(|(_, name)| !name.is_empty() && !mapped.iter().any(|m| m == *name))
+                .map(|(index, name)| (index, *name))
+                .collect(),
+        };
+
+        let mut inventory = Inventory::new();
+        for (line, record) in records {
+            let cell = |index: Option<usize>| {
+                index
+                    .and_then(|index| record.get(index))
+                    .map(|value| value.trim())
+                    .filter(|value| !value.is_empty())
+            };
+            let error = |message: String| csv_error(source, format!("line {line}: {message}"));
+            let name = cell(Some(name_column)).ok_or_else(|| error("empty host name".into()))?;
+            if record.len() > header.len() {
+                return Err(error(format!(
+                    "{} fields, the header has {}",
+                    record.len(),
+                    header.len()
+                )));
+            }
+            if inventory.hosts.get(name).is_some() {
+                return Err(error(format!("duplicate host `{name}`")));
+            }
+
+            let mut host = Host::new(name);
+            host.hostname = cell(column(&options.hostname)).map(str::to_string);
+            host.port = cell(column(&options.port))
+                .map(|port| {
+                    port.parse::<u16>()
+                        .map_err(|_| error(format!("invalid port `{port}`")))
+                })
+                .transpose()?;
+            host.username = cell(column(&options.username)).map(str::to_string);
+            host.platform = cell(column(&options.platform)).map(str::to_string);
+            let groups: Vec<String> = cell(column(&options.groups))
+                .into_iter()
+                .flat_map(|groups| groups.split(options.group_separator))
+                .map(str::trim)
+                .filter(|group| !group.is_empty())
+                .map(str::to_string)
+                .collect();
+            host.groups = (!groups.is_empty()).then_some(ParentGroups(groups));
+            let data: Map<String, Value> = data_columns
+                .iter()
+                .filter_map(|(index, name)| {
+                    Some((name.to_string(), Value::from(cell(Some(*index))?)))
+                })
+                .collect();
+            host.data = (!data.is_empty()).then(|| Data::new(Value::Object(data)));
+            inventory.hosts.add_host(host);
+        }
+        Ok(inventory)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_csv_default_columns() {
+        let csv = "\
+name,hostname,port,platform,groups,site,rack
+r1,10.0.0.1,22,eos,core;ams,ams,\"A1, row 2\"
+r2,10.0.0.2,,junos,,lon,
+
+r3,,830,,edge,,
+";
+        let inventory = Inventory::from_csv_str(csv, &CsvOptions::default()).unwrap();
+        assert_eq!(inventory.hosts.len(), 3);
+        let r1 = &inventory.hosts["r1"];
+        assert_eq!(r1.port, Some(22));
+        assert_eq!(r1.platform.as_deref(), Some("eos"));
+        assert_eq!(r1.groups.as_ref().unwrap().len(), 2);
+        assert_eq!(
+            **r1.data.as_ref().unwrap(),
+            serde_json::json!({ "site": "ams", "rack": "A1, row 2" })
+        );
+        let r2 = &inventory.hosts["r2"];
+        assert!(r2.port.is_none() && r2.groups.is_none());
+        assert!(inventory.hosts["r3"].data.is_none());
+    }
+
+    #[test]
+    fn test_from_csv_custom_mapping() {
+        let options = CsvOptions {
+            name: "Device".to_string(),
+            hostname: "IP".to_string(),
+            groups: "Roles".to_string(),
+            group_separator: '|',
+            data: Some(vec!["Serial".to_string()]),
+            delimiter: ';',
+            ..CsvOptions::default()
+        };
+        let csv = "Device;IP;Roles;Serial;Notes\r\nsw1;192.0.2.1;access|ams;FOC123;\"said \"\"hi\"\"\"\r\n";
+        let inventory = Inventory::from_csv_str(csv, &options).unwrap();
+        let sw1 = &inventory.hosts["sw1"];
+        assert_eq!(sw1.hostname.as_deref(), Some("192.0.2.1"));
+        assert_eq!(sw1.groups.as_ref().unwrap()[1], "ams");
+        assert_eq!(
+            **sw1.data.as_ref().unwrap(),
+            serde_json::json!({ "Serial": "FOC123" })
+        );
+    }
+
+    #[test]
+    fn test_from_csv_errors() {
+        let options = CsvOptions::default();
+        let error = |csv: &str| {
+            Inventory::from_csv_str(csv, &options)
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(error("hostname\n10.0.0.1\n").ends_with("missing `name` column"));
+        assert!(error("name,port\nr1,ssh\n").ends_with("line 2: invalid port `ssh`"));
+        assert!(error("name\nr1\nr1\n").ends_with("line 3: duplicate host `r1`"));
+        assert!(error("name\n\"r1\n").ends_with("line 2: unterminated quoted field"));
+    }
+}
@@ -0,0 +1,311 @@
+use super::Inventory;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+/// The diagram rendered by [`Inventory::to_dot`] and [`Inventory::to_mermaid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diagram {
+    /// Hosts pointing at their groups and groups pointing at their parents.
+    GroupHierarchy,
+    /// Hosts connected to the neighbors listed in their data, see
+    /// [`DiagramOptions::adjacency_key`].
+    Adjacency,
+}
+
+/// Options shared by the DOT and Mermaid renderers.
+#[derive(Debug, Clone)]
+pub struct DiagramOptions {
+    /// Hosts drawn in red, e.g. the failed hosts of a run.
+    pub failed_hosts: BTreeSet<String>,
+    /// Data key holding a host's neighbors, either as host names or as
+    /// objects with a `host` field. Defaults to `neighbors`.
+    pub adjacency_key: String,
+}
+
+impl Default for DiagramOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DiagramOptions {
+    pub fn new() -> Self {
+        DiagramOptions {
+            failed_hosts: BTreeSet::new(),
+            adjacency_key: "neighbors".to_string(),
+        }
+    }
+
+    pub fn failed_hosts<I, S>(mut self, hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.failed_hosts = hosts.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn adjacency_key(mut self, key: &str) -> Self {
+        self.adjacency_key = key.to_string();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum NodeKind {
+    Group,
+    Host,
+}
+
+type Node = (NodeKind, String);
+
+/// Renderer independent graph built from the inventory.
+struct Graph {
+    directed: bool,
+    nodes: BTreeSet<Node>,
+    edges: BTreeSet<(Node, Node)>,
+}
+
+impl Graph {
+    fn build(inventory: &Inventory, diagram: Diagram, options: &DiagramOptions) -> Graph {
+        let mut graph = Graph {
+            directed: diagram == Diagram::GroupHierarchy,
+            nodes: BTreeSet::new(),
+            edges: BTreeSet::new(),
+        };
+        match diagram {
+            Diagram::GroupHierarchy => {
+                for host in inventory.hosts.values() {
+                    let host_node = (NodeKind::Host, host.name.clone());
+                    graph.nodes.insert(host_node.clone());
+                    for group in host.groups.iter().flat_map(|groups| groups.iter()) {
+                        let group_node = (NodeKind::Group, group.clone());
+                        graph.nodes.insert(group_node.clone());
+                        graph.edges.insert((host_node.clone(), group_node));
+                    }
+                }
+                for (name, group) in inventory.groups.iter().flat_map(|groups| groups.iter()) {
+                    let group_node = (NodeKind::Group, name.to_string());
+                    graph.nodes.insert(group_node.clone());
+                    for parent in group.groups.iter().flat_map(|groups| groups.iter()) {
+                        let parent_node = (NodeKind::Group, parent.clone());
+                        graph.nodes.insert(parent_node.clone());
+                        graph.edges.insert((group_node.clone(), parent_node));
+                    }
+                }
+            }
+            Diagram::Adjacency => {
+                for host in inventory.hosts.values() {
+                    graph.nodes.insert((NodeKind::Host, host.name.clone()));
+                    let neighbors = host
+                        .data
+                        .as_ref()
+                        .and_then(|data| data.get(&options.adjacency_key))
+                        .and_then(Value::as_array);
+                    for neighbor in neighbors.into_iter().flatten() {
+                        let neighbor = match neighbor {
+                            Value::String(name) => Some(name.as_str()),
+                            Value::Object(map) => map.get("host").and_then(Value::as_str),
+                            _ => None,
+                        };
+                        let Some(neighbor) = neighbor else {
+                            continue;
+                        };
+                        graph.nodes.insert((NodeKind::Host, neighbor.to_string()));
+                        // Links are undirected, store each pair once.
+                        let (a, b) = if host.name.as_str() <= neighbor {
+                            (host.name.clone(), neighbor.to_string())
+                        } else {
+                            (neighbor.to_string(), host.name.clone())
+                        };
+                        graph
+                            .edges
+                            .insert(((NodeKind::Host, a), (NodeKind::Host, b)));
+                    }
+                }
+            }
+        }
+        graph
+    }
+}
+
+fn dot_quote(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Node ids are prefixed by kind so a host and a group of the same name
+/// stay apart.
+fn dot_id(kind: NodeKind, name: &str) -> String {
+    let prefix = match kind {
+        NodeKind::Group => "g_",
+        NodeKind::Host => "h_",
+    };
+    dot_quote(&format!("{prefix}{name}"))
+}
+
+/// Like [`dot_id`], escaping the name to the characters Mermaid accepts in
+/// ids: `_` becomes `__` and other bytes outside `[A-Za-z0-9]` become `_`
+/// followed by two hex digits, so distinct names keep distinct ids.
+fn mermaid_id(kind: NodeKind, name: &str) -> String {
+    let mut id = String::from(match kind {
+        NodeKind::Group => "g_",
+        NodeKind::Host => "h_",
+    });
+    for byte in name.bytes() {
+        match byte {
+            b'_' => id.push_str("__"),
+            _ if byte.is_ascii_alphanumeric() => id.push(byte as char),
+            _ => id.push_str(&format!("_{byte:02X}")),
+        }
+    }
+    id
+}
+
+impl Inventory {
+    /// Renders the diagram as a Graphviz DOT document.
+    ///
+    /// Groups are drawn as boxes and hosts as ellipses; hosts listed in
+    /// `options.failed_hosts` are drawn in red.
+    pub fn to_dot(&self, diagram: Diagram, options: &DiagramOptions) -> String {
+        let graph = Graph::build(self, diagram, options);
+        let (keyword, arrow) = if graph.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+
+        let mut out = String::new();
+        let _ = writeln!(out, "{keyword} inventory {{");
+        for (kind, name) in &graph.nodes {
+            let label = format!("label={}", dot_quote(name));
+            let mut attrs = vec![
+                label.as_str(),
+                match kind {
+                    NodeKind::Group => "shape=box",
+                    NodeKind::Host => "shape=ellipse",
+                },
+            ];
+            if *kind == NodeKind::Host && options.failed_hosts.contains(name) {
+                attrs.push("color=red");
+                attrs.push("fontcolor=red");
+            }
+            let _ = writeln!(out, "    {} [{}];", dot_id(*kind, name), attrs.join(", "));
+        }
+        for ((from_kind, from), (to_kind, to)) in &graph.edges {
+            let from = dot_id(*from_kind, from);
+            let to = dot_id(*to_kind, to);
+            let _ = writeln!(out, "    {from} {arrow} {to};");
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Renders the diagram as a Mermaid flowchart.
+    ///
+    /// Hosts listed in `options.failed_hosts` get the `failed` class, which
+    /// is styled red.
+    pub fn to_mermaid(&self, diagram: Diagram, options: &DiagramOptions) -> String {
+        let graph = Graph::build(self, diagram, options);
+        let arrow = if graph.directed { "-->" } else { "---" };
+
+        let mut out = String::from("flowchart LR\n");
+        let mut failed = Vec::new();
+        for (kind, name) in &graph.nodes {
+            let id = mermaid_id(*kind, name);
+            let label = name.replace('"', "#quot;");
+            match kind {
+                NodeKind::Group => {
+                    let _ = writeln!(out, "    {id}[\"{label}\"]");
+                }
+                NodeKind::Host => {
+                    let _ = writeln!(out, "    {id}([\"{label}\"])");
+                    if options.failed_hosts.contains(name) {
+                        failed.push(id);
+                    }
+                }
+            }
+        }
+        for ((from_kind, from), (to_kind, to)) in &graph.edges {
+            let from = mermaid_id(*from_kind, from);
+            let to = mermaid_id(*to_kind, to);
+            let _ = writeln!(out, "    {from} {arrow} {to}");
+        }
+        if !failed.is_empty() {
+            out.push_str("    classDef failed fill:#fdd,stroke:#c00,color:#c00\n");
+            let _ = writeln!(out, "    class {} failed", failed.join(","));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::{BaseBuilderHost, Group, Groups, Host, Hosts, ParentGroups};
+    use crate::CustomTreeMap;
+    use serde_json::json;
+
+    fn inventory() -> Inventory {
+        let mut hosts = Hosts::new();
+        hosts.add_host(
+            Host::builder("r1")
+                .group("core")
+                .data_json(json!({ "neighbors": ["r2", { "host": "s1", "port": "Et1" }] }))
+                .build(),
+        );
+        hosts.add_host(
+            Host::builder("r2")
+                .group("core")
+                .data_json(json!({ "neighbors": ["r1"] }))
+                .build(),
+        );
+        hosts.add_host(Host::builder("s1").group("access").build());
+
+        let mut groups = CustomTreeMap::new();
+        groups.insert(
+            "core",
+            Group {
+                groups: Some(ParentGroups(vec!["dc1".to_string()])),
+                ..Group::new()
+            },
+        );
+        Inventory {
+            groups: Some(Groups(groups)),
+            ..Inventory::builder().hosts(hosts).build()
+        }
+    }
+
+    #[test]
+    fn test_group_hierarchy_dot() {
+        let options = DiagramOptions::new().failed_hosts(["r2"]);
+        let dot = inventory().to_dot(Diagram::GroupHierarchy, &options);
+        assert!(dot.starts_with("digraph inventory {"));
+        assert!(dot.contains("\"g_core\" [label=\"core\", shape=box];"));
+        assert!(dot.contains("\"h_r2\" [label=\"r2\", shape=ellipse, color=red, fontcolor=red];"));
+        assert!(dot.contains("\"h_r1\" -> \"g_core\";"));
+        assert!(dot.contains("\"g_core\" -> \"g_dc1\";"));
+    }
+
+    #[test]
+    fn test_adjacency_mermaid() {
+        let options = DiagramOptions::new().failed_hosts(["s1"]);
+        let mermaid = inventory().to_mermaid(Diagram::Adjacency, &options);
+        let edges: Vec<&str> = mermaid.lines().filter(|l| l.contains("---")).collect();
+        assert_eq!(edges, vec!["    h_r1 --- h_r2", "    h_r1 --- h_s1"]);
+        assert!(mermaid.contains("    class h_s1 failed"));
+    }
+
+    #[test]
+    fn test_node_ids_do_not_collide() {
+        let mut hosts = Hosts::new();
+        hosts.add_host(Host::builder("core").group("core").build());
+        let inventory = Inventory::builder().hosts(hosts).build();
+        let dot = inventory.to_dot(Diagram::GroupHierarchy, &DiagramOptions::new());
+        assert!(dot.contains("\"h_core\" -> \"g_core\";"));
+
+        assert_eq!(mermaid_id(NodeKind::Host, "r-1"), "h_r_2D1");
+        assert_eq!(mermaid_id(NodeKind::Host, "r_1"), "h_r__1");
+        assert_eq!(mermaid_id(NodeKind::Host, "r_2D1"), "h_r__2D1");
+    }
+}
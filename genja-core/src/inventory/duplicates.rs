@@ -0,0 +1,370 @@
+use super::{Host, Hosts, InventoryError};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// What to do when an added host collides with a host already present.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicatePolicy {
+    /// Fail with `InventoryError::Duplicate`.
+    Error,
+    /// Keep the host already present and drop the added one.
+    KeepFirst,
+    /// Replace the host already present. This is what `Hosts::add_host` does.
+    #[default]
+    KeepLast,
+    /// Merge the added host into the one already present, see
+    /// [`Host::merge_from`].
+    Merge,
+}
+
+/// Duplicate handling used by [`Hosts::insert_with_policy`] and
+/// [`Hosts::merge_hosts`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+pub struct DuplicatePolicies {
+    /// Policy for two hosts with the same name.
+    #[serde(default)]
+    pub names: DuplicatePolicy,
+    /// Policy for two differently named hosts with the same hostname and
+    /// port. Duplicate management addresses are not checked when unset.
+    #[serde(default)]
+    pub hostnames: Option<DuplicatePolicy>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictKind {
+    Name,
+    Hostname,
+}
+
+/// A collision detected while adding a host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    pub kind: ConflictKind,
+    /// The duplicated host name, or the `hostname[:port]` address.
+    pub key: String,
+    pub existing: String,
+    pub incoming: String,
+    pub resolution: DuplicatePolicy,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ConflictKind::Name => write!(f, "duplicate host name `{}`", self.key)?,
+            ConflictKind::Hostname => write!(
+                f,
+                "duplicate address `{}` on hosts `{}` and `{}`",
+                self.key, self.existing, self.incoming
+            )?,
+        }
+        let resolution = match self.resolution {
+            DuplicatePolicy::Error => "rejected",
+            DuplicatePolicy::KeepFirst => "kept first",
+            DuplicatePolicy::KeepLast => "kept last",
+            DuplicatePolicy::Merge => "merged",
+        };
+        write!(f, ": {resolution}")
+    }
+}
+
+/// Conflicts resolved while adding hosts, in the order they were found.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConflictReport {
+    pub conflicts: Vec<Conflict>,
+}
+
+impl ConflictReport {
+    pub fn is_empty(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.conflicts.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Conflict> {
+        self.conflicts.iter()
+    }
+}
+
+impl fmt::Display for ConflictReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for conflict in &self.conflicts {
+            writeln!(f, "{conflict}")?;
+        }
+        Ok(())
+    }
+}
+
+fn address_key(host: &Host) -> Option<String> {
+    let hostname = host.hostname.as_deref()?;
+    Some(match host.port {
+        Some(port) => format!("{hostname}:{port}"),
+        None => hostname.to_string(),
+    })
+}
+
+/// Recursively merges `source` into `target`. Objects are merged key by key,
+/// any other value in `source` replaces the one in `target`.
+pub(crate) fn merge_json(target: &mut Value, source: Value) {
+    match (target, source) {
+        (Value::Object(target), Value::Object(source)) => {
+            for (key, value) in source {
+                match target.get_mut(&key) {
+                    Some(existing) => merge_json(existing, value),
+                    None => {
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (target, source) => *target = source,
+    }
+}
+
+impl Host {
+    /// Merges `other` into this host.
+    ///
+    /// Fields set on `other` win, groups are appended without duplicates,
+    /// data is merged recursively and connection options are replaced per
+    /// plugin. The host keeps its own name.
+    pub fn merge_from(&mut self, other: Host) {
+        macro_rules! take {
+            ($($field:ident),*) => {
+                $(if other.$field.is_some() {
+                    self.$field = other.$field;
+                })*
+            };
+        }
+        take!(hostname, port, username, password, platform, defaults);
+
+        if let Some(groups) = other.groups {
+            let own = self.groups.get_or_insert_with(Default::default);
+            for group in groups.iter() {
+                if !own.contains(group) {
+                    own.push(group.clone());
+                }
+            }
+        }
+        if let Some(data) = other.data {
+            match self.data.as_mut() {
+                Some(own) => merge_json(own, (*data).clone()),
+                None => self.data = Some(data),
+            }
+        }
+        if let Some(options) = other.connection_options {
+            let own = self.connection_options.get_or_insert_with(Default::default);
+            for (plugin, options) in options.iter() {
+                own.insert(plugin.as_str(), options.clone());
+            }
+        }
+        self.resolved_connection_params.clear();
+    }
+}
+
+impl Hosts {
+    /// Adds `host`, resolving name and address collisions with `policies`.
+    ///
+    /// Resolved collisions are appended to `report`. With
+    /// `DuplicatePolicy::Error` the host is not added and
+    /// `InventoryError::Duplicate` is returned.
+    pub fn insert_with_policy(
+        &mut self,
+        host: Host,
+        policies: &DuplicatePolicies,
+        report: &mut ConflictReport,
+    ) -> Result<(), InventoryError> {
+        let mut resolve = |kind, key: String, existing: &str, policy| {
+            let conflict = Conflict {
+                kind,
+                key,
+                existing: existing.to_string(),
+                incoming: host.name.clone(),
+                resolution: policy,
+            };
+            if policy == DuplicatePolicy::Error {
+                return Err(InventoryError::Duplicate(conflict));
+            }
+            log::warn!("{conflict}");
+            report.conflicts.push(conflict);
+            Ok(policy)
+        };
+
+        if self.get(&host.name).is_some() {
+            match resolve(
+                ConflictKind::Name,
+                host.name.clone(),
+                &host.name,
+                policies.names,
+            )? {
+                DuplicatePolicy::KeepFirst => return Ok(()),
+                DuplicatePolicy::Merge => {
+                    let existing = self.get_mut(host.name.as_str()).expect("checked above");
+                    existing.merge_from(host);
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+
+        if let (Some(policy), Some(address)) = (policies.hostnames, address_key(&host)) {
+            let existing = self
+                .values()
+                .find(|other| {
+                    other.name != host.name && address_key(other).as_ref() == Some(&address)
+                })
+                .map(|other| other.name.clone());
+            if let Some(existing) = existing {
+                match resolve(ConflictKind::Hostname, address, &existing, policy)? {
+                    DuplicatePolicy::KeepFirst => return Ok(()),
+                    DuplicatePolicy::Merge => {
+                        let target = self.get_mut(existing.as_str()).expect("found above");
+                        target.merge_from(host);
+                        return Ok(());
+                    }
+                    _ => {
+                        self.remove(existing.as_str());
+                    }
+                }
+            }
+        }
+
+        self.add_host(host);
+        Ok(())
+    }
+
+    /// Adds all hosts of `other`, e.g. from a second inventory source, and
+    /// returns the collisions that were resolved.
+    pub fn merge_hosts(
+        &mut self,
+        other: Hosts,
+        policies: &DuplicatePolicies,
+    ) -> Result<ConflictReport, InventoryError> {
+        let mut report = ConflictReport::default();
+        let mut other = other;
+        for host in std::mem::take(&mut **other).into_values() {
+            self.insert_with_policy(host, policies, &mut report)?;
+        }
+        Ok(report)
+    }
+
+    /// Returns the `hostname[:port]` addresses shared by more than one host,
+    /// with the names of the hosts using them.
+    pub fn duplicate_hostnames(&self) -> BTreeMap<String, Vec<String>> {
+        let mut addresses: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for host in self.values() {
+            if let Some(address) = address_key(host) {
+                addresses
+                    .entry(address)
+                    .or_default()
+                    .push(host.name.clone());
+            }
+        }
+        addresses.retain(|_, names| names.len() > 1);
+        addresses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::BaseBuilderHost;
+    use serde_json::json;
+
+    fn existing() -> Hosts {
+        let mut hosts = Hosts::new();
+        hosts.add_host(
+            Host::builder("r1")
+                .hostname("10.0.0.1")
+                .group("core")
+                .data_json(json!({ "site": { "name": "hq", "rack": 1 } }))
+                .build(),
+        );
+        hosts
+    }
+
+    fn incoming() -> Hosts {
+        let mut hosts = Hosts::new();
+        hosts.add_host(
+            Host::builder("r1")
+                .platform("eos")
+                .group("edge")
+                .data_json(json!({ "site": { "rack": 2 } }))
+                .build(),
+        );
+        hosts.add_host(Host::builder("r9").hostname("10.0.0.1").build());
+        hosts
+    }
+
+    #[test]
+    fn test_merge_names_and_keep_first_addresses() {
+        let mut hosts = existing();
+        let policies = DuplicatePolicies {
+            names: DuplicatePolicy::Merge,
+            hostnames: Some(DuplicatePolicy::KeepFirst),
+        };
+        let report = hosts.merge_hosts(incoming(), &policies).unwrap();
+
+        assert_eq!(report.len(), 2);
+        assert_eq!(
+            report.to_string(),
+            "duplicate host name `r1`: merged\n\
+             duplicate address `10.0.0.1` on hosts `r1` and `r9`: kept first\n"
+        );
+        let r1 = hosts.get("r1").unwrap();
+        assert_eq!(r1.hostname.as_deref(), Some("10.0.0.1"));
+        assert_eq!(r1.platform.as_deref(), Some("eos"));
+        assert_eq!(r1.groups.as_ref().unwrap().as_slice(), ["core", "edge"]);
+        assert_eq!(
+            **r1.data.as_ref().unwrap(),
+            json!({ "site": { "name": "hq", "rack": 2 } })
+        );
+        assert!(hosts.get("r9").is_none());
+    }
+
+    #[test]
+    fn test_keep_last_address_replaces_existing_host() {
+        let mut hosts = existing();
+        let policies = DuplicatePolicies {
+            names: DuplicatePolicy::KeepFirst,
+            hostnames: Some(DuplicatePolicy::KeepLast),
+        };
+        hosts.merge_hosts(incoming(), &policies).unwrap();
+        let names: Vec<&str> = hosts.keys_str().collect();
+        assert_eq!(names, ["r9"]);
+    }
+
+    #[test]
+    fn test_error_policy_rejects_duplicates() {
+        let mut hosts = existing();
+        let policies = DuplicatePolicies {
+            names: DuplicatePolicy::Error,
+            hostnames: None,
+        };
+        let error = hosts.merge_hosts(incoming(), &policies).unwrap_err();
+        assert!(matches!(
+            error,
+            InventoryError::Duplicate(Conflict {
+                kind: ConflictKind::Name,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_duplicate_hostnames() {
+        let mut hosts = existing();
+        hosts.add_host(Host::builder("r2").hostname("10.0.0.1").build());
+        hosts.add_host(Host::builder("r3").hostname("10.0.0.1").port(2222).build());
+        let duplicates = hosts.duplicate_hostnames();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates["10.0.0.1"], ["r1", "r2"]);
+    }
+}
@@ -0,0 +1,149 @@
+//! Composition of inventory files with `extends`.
+//!
+//! A file can list base files to layer on top of:
+//!
+//! ```yaml
+//! extends: [../base.yaml, platforms.yaml]
+//! hosts:
+//!   r1: { name: r1, hostname: 10.1.0.1 }
+//! ```
+//!
+//! Merge semantics:
+//!
+//! * Bases are merged in the order listed, then the file itself on top, so
+//!   later entries win.
+//! * Objects (`hosts`, `groups`, a host's `data`, ...) are merged key by key
+//!   at any depth.
+//! * Any other value, lists included, replaces the one from the bases.
+//!   A host's `groups` list is therefore replaced, not appended to.
+//! * Bases can extend other files. Relative paths are resolved against the
+//!   directory of the file that names them. Cycles are rejected.
+
+use super::duplicates::merge_json;
+use super::{InventoryError, LoadStage};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Top level key listing the files a document extends.
+const EXTENDS_KEY: &str = "extends";
+
+/// Reads the YAML or JSON file at `path` and merges in the files it
+/// extends, see the [module documentation](self). The returned document
+/// has no `extends` key.
+pub fn compose_file(path: impl AsRef<Path>) -> Result<Value, InventoryError> {
+    compose(path.as_ref(), &mut Vec::new())
+}
+
+fn compose_error(path: &Path, message: impl std::fmt::Display) -> InventoryError {
+    InventoryError::Load {
+        stage: LoadStage::Parse,
+        message: format!("`{}`: {message}", path.display()),
+    }
+}
+
+fn compose(path: &Path, chain: &mut Vec<PathBuf>) -> Result<Value, InventoryError> {
+    let canonical = path
+        .canonicalize()
+        .map_err(|error| compose_error(path, error))?;
+    if chain.contains(&canonical) {
+        return Err(compose_error(path, "extends itself"));
+    }
+    let text = fs::read_to_string(path).map_err(|error| compose_error(path, error))?;
+    let mut document: Value =
+        serde_yaml::from_str(&text).map_err(|error| compose_error(path, error))?;
+
+    let bases = match document.as_object_mut().map(|map| map.remove(EXTENDS_KEY)) {
+        None | Some(None) => return Ok(document),
+        Some(Some(Value::String(base))) => vec![base],
+        Some(Some(Value::Array(bases))) => bases
+            .into_iter()
+            .map(|base| match base {
+                Value::String(base) => Ok(base),
+                other => Err(compose_error(
+                    path,
+                    format!("expected a file name in `extends`, found {other}"),
+                )),
+            })
+            .collect::<Result<_, _>>()?,
+        Some(Some(other)) => {
+            return Err(compose_error(
+                path,
+                format!("expected a file name or a list in `extends`, found {other}"),
+            ))
+        }
+    };
+
+    chain.push(canonical);
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let mut composed = Value::Object(Default::default());
+    for base in bases {
+        merge_json(&mut composed, compose(&dir.join(base), chain)?);
+    }
+    chain.pop();
+    merge_json(&mut composed, document);
+    Ok(composed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::{Inventory, LoadPipeline};
+    use crate::test_util::TempDir;
+
+    #[test]
+    fn test_extends_layers_files() {
+        let dir = TempDir::new("extends");
+        dir.write(
+            "base.yaml",
+            "
+groups:
+  core: { platform: eos, data: { ntp: [10.0.0.1], syslog: 10.0.0.2 } }
+defaults: { username: admin }
+",
+        );
+        dir.write(
+            "sites/common.json",
+            r#"{ "extends": "../base.yaml", "defaults": { "port": 22 } }"#,
+        );
+        let site = dir.write(
+            "sites/hq.yaml",
+            "
+extends: [common.json]
+hosts:
+  r1: { name: r1, groups: [core] }
+groups:
+  core: { data: { ntp: [10.1.0.1] } }
+",
+        );
+
+        let document = compose_file(&site).unwrap();
+        assert!(document.get("extends").is_none());
+        let inventory: Inventory = serde_json::from_value(document).unwrap();
+        let core = inventory.groups.as_ref().unwrap().get("core").unwrap();
+        assert_eq!(core.platform.as_deref(), Some("eos"));
+        assert_eq!(
+            **core.data.as_ref().unwrap(),
+            serde_json::json!({ "ntp": ["10.1.0.1"], "syslog": "10.0.0.2" })
+        );
+        let defaults = inventory.defaults.as_ref().unwrap();
+        assert_eq!(defaults["username"], "admin");
+        assert_eq!(defaults["port"], 22);
+
+        let (inventory, _) = LoadPipeline::new().load_file(&site).unwrap();
+        assert!(inventory.hosts.get("r1").is_some());
+    }
+
+    #[test]
+    fn test_extends_cycles_are_rejected() {
+        let dir = TempDir::new("extends-cycle");
+        dir.write("a.yaml", "extends: b.yaml");
+        dir.write("b.yaml", "extends: [a.yaml]");
+        let error = compose_file(dir.join("a.yaml")).unwrap_err().to_string();
+        assert!(error.ends_with("a.yaml`: extends itself"), "{error}");
+
+        dir.write("c.yaml", "extends: [1]");
+        let error = compose_file(dir.join("c.yaml")).unwrap_err().to_string();
+        assert!(error.ends_with("expected a file name in `extends`, found 1"));
+    }
+}
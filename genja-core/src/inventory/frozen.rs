@@ -0,0 +1,108 @@
+use super::Inventory;
+use std::ops::Deref;
+use std::sync::{Arc, RwLock};
+
+/// An inventory that can no longer be changed, see [`Inventory::freeze`].
+///
+/// It derefs to [`Inventory`] for reading only. The transform function is
+/// dropped on freezing, so hosts selected from a frozen inventory always
+/// match the hosts it holds. To make changes, [`FrozenInventory::thaw`] a
+/// copy and freeze it again.
+#[derive(Debug)]
+pub struct FrozenInventory(Inventory);
+
+impl Deref for FrozenInventory {
+    type Target = Inventory;
+
+    fn deref(&self) -> &Inventory {
+        &self.0
+    }
+}
+
+impl FrozenInventory {
+    /// Returns an editable inventory. The frozen one is reused when this is
+    /// the last reference to it and copied otherwise. Host data stays
+    /// shared with the frozen copy until it is replaced.
+    ///
+    /// The connection manager is shared with the frozen inventory either
+    /// way, so connections opened through one are visible to the other.
+    pub fn thaw(self: Arc<Self>) -> Inventory {
+        Arc::try_unwrap(self)
+            .map(|frozen| frozen.0)
+            .unwrap_or_else(|frozen| frozen.0.clone())
+    }
+}
+
+impl Inventory {
+    /// Freezes the inventory for use by [`Genja`](crate::Genja).
+    ///
+    /// Run the transform function first, e.g. through
+    /// [`LoadPipeline`](super::LoadPipeline): it is dropped here, and a
+    /// warning is logged if it was set.
+    pub fn freeze(mut self) -> Arc<FrozenInventory> {
+        if self.transform_function.take().is_some() {
+            log::warn!("dropping the transform function of a frozen inventory");
+        }
+        Arc::new(FrozenInventory(self))
+    }
+}
+
+/// The current inventory of a running process, replaced on reload.
+///
+/// Every replacement bumps the generation, which [`Genja`](crate::Genja)
+/// views compare against to notice that their inventory is outdated.
+#[derive(Debug)]
+pub struct SharedInventory {
+    current: RwLock<(u64, Arc<FrozenInventory>)>,
+}
+
+impl SharedInventory {
+    pub fn new(inventory: Arc<FrozenInventory>) -> Self {
+        SharedInventory {
+            current: RwLock::new((0, inventory)),
+        }
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.current.read().unwrap_or_else(|e| e.into_inner()).0
+    }
+
+    /// The current generation and inventory.
+    pub fn current(&self) -> (u64, Arc<FrozenInventory>) {
+        let current = self.current.read().unwrap_or_else(|e| e.into_inner());
+        (current.0, Arc::clone(&current.1))
+    }
+
+    /// Replaces the inventory and returns the new generation.
+    pub fn replace(&self, inventory: Arc<FrozenInventory>) -> u64 {
+        let mut current = self.current.write().unwrap_or_else(|e| e.into_inner());
+        current.0 += 1;
+        current.1 = inventory;
+        current.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::{Host, TransformFunction};
+
+    #[test]
+    fn test_freeze_and_thaw() {
+        let mut inventory = Inventory::new();
+        inventory.hosts.add_host(Host::new("r1"));
+        inventory.transform_function = Some(TransformFunction::new(|_, _| {}));
+        let frozen = inventory.freeze();
+        assert!(frozen.transform_function.is_none());
+        assert!(frozen.hosts.get("r1").is_some());
+
+        let shared = Arc::clone(&frozen);
+        let mut copy = shared.thaw();
+        copy.hosts.add_host(Host::new("r2"));
+        assert_eq!(frozen.hosts.len(), 1);
+        assert!(Arc::ptr_eq(&copy.connections, &frozen.connections));
+
+        let thawed = frozen.thaw();
+        assert_eq!(thawed.hosts.len(), 1);
+    }
+}
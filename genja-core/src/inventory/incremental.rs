@@ -0,0 +1,254 @@
+use super::{Host, Hosts};
+use crate::types::CowStr;
+use serde::de::{DeserializeSeed, IgnoredAny, MapAccess, Visitor};
+use serde::Deserializer;
+use std::fmt;
+use std::io::Read;
+
+/// Progress reported by [`IncrementalLoader`] after every chunk of parsed
+/// hosts, followed by one final report with `done` set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadProgress {
+    /// Number of host entries read from the source so far.
+    pub parsed: usize,
+    /// Number of hosts kept after filtering.
+    pub kept: usize,
+    /// `true` for the final report once loading has finished.
+    pub done: bool,
+}
+
+type NameFilter<'a> = Box<dyn Fn(&str) -> bool + 'a>;
+type HostFilter<'a> = Box<dyn Fn(&Host) -> bool + 'a>;
+type ProgressCallback<'a> = Box<dyn FnMut(LoadProgress) + 'a>;
+
+/// Streams a `name -> host` map from a serde source one entry at a time.
+///
+/// Hosts rejected by the filters are never kept in memory, and hosts rejected
+/// by the name filter are skipped without being deserialized into a [`Host`].
+/// Once the optional limit is reached the remaining entries are skipped, but
+/// they are still read and checked for syntax: the whole source is parsed.
+///
+/// # Examples
+///
+/// ```
+/// # use genja_core::inventory::IncrementalLoader;
+/// let source = r#"{
+///     "r1": { "name": "r1", "platform": "eos" },
+///     "r2": { "name": "r2", "platform": "ios" },
+///     "r3": { "name": "r3", "platform": "eos" }
+/// }"#;
+///
+/// let mut reports = Vec::new();
+/// let hosts = IncrementalLoader::new()
+///     .chunk_size(2)
+///     .filter(|host| host.platform.as_deref() == Some("eos"))
+///     .on_progress(|progress| reports.push(progress.parsed))
+///     .load_json(source.as_bytes())
+///     .unwrap();
+///
+/// assert_eq!(hosts.len(), 2);
+/// assert_eq!(reports, vec![2, 3]);
+/// ```
+pub struct IncrementalLoader<'a> {
+    chunk_size: usize,
+    limit: Option<usize>,
+    name_filter: Option<NameFilter<'a>>,
+    filter: Option<HostFilter<'a>>,
+    progress: Option<ProgressCallback<'a>>,
+}
+
+impl Default for IncrementalLoader<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> IncrementalLoader<'a> {
+    pub fn new() -> Self {
+        IncrementalLoader {
+            chunk_size: 1000,
+            limit: None,
+            name_filter: None,
+            filter: None,
+            progress: None,
+        }
+    }
+
+    /// Number of parsed hosts between two progress reports. Defaults to 1000.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Stops keeping hosts once `limit` hosts have been loaded. The rest of
+    /// the source is still parsed, see the [type documentation](Self).
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Filters on the host name before the host body is deserialized.
+    pub fn name_filter(mut self, filter: impl Fn(&str) -> bool + 'a) -> Self {
+        self.name_filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Filters on the fully deserialized host.
+    pub fn filter(mut self, filter: impl Fn(&Host) -> bool + 'a) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Sets the callback invoked after every chunk and once at the end.
+    pub fn on_progress(mut self, callback: impl FnMut(LoadProgress) + 'a) -> Self {
+        self.progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Loads hosts from any serde deserializer producing a map.
+    ///
+    /// Deserializers over borrowed input, such as
+    /// `serde_json::Deserializer::from_str`, lend host names without
+    /// allocating them for skipped hosts.
+    pub fn load<'de, D>(self, deserializer: D) -> Result<Hosts, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+
+    /// Loads hosts from a JSON reader. Readers cannot lend strings, so every
+    /// host name is allocated, skipped or not.
+    pub fn load_json<R: Read>(self, reader: R) -> Result<Hosts, serde_json::Error> {
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        let hosts = self.load(&mut deserializer)?;
+        deserializer.end()?;
+        Ok(hosts)
+    }
+
+    /// Loads hosts from a YAML reader. Like [`load_json`](Self::load_json),
+    /// every host name is allocated.
+    pub fn load_yaml<R: Read>(self, reader: R) -> Result<Hosts, serde_yaml::Error> {
+        self.load(serde_yaml::Deserializer::from_reader(reader))
+    }
+
+    fn report(&mut self, progress: LoadProgress) {
+        if let Some(callback) = self.progress.as_mut() {
+            callback(progress);
+        }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for IncrementalLoader<'_> {
+    type Value = Hosts;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        self.load(deserializer)
+    }
+}
+
+impl<'de> Visitor<'de> for IncrementalLoader<'_> {
+    type Value = Hosts;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of host names to hosts")
+    }
+
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut hosts = Hosts::new();
+        let mut parsed = 0;
+
+        // Keys are read as `CowStr`: with borrowed input skipped hosts don't
+        // allocate their name, readers such as `load_json` always allocate.
+        while let Some(CowStr(name)) = map.next_key()? {
+            let full = self.limit.is_some_and(|limit| hosts.len() >= limit);
+            let wanted = self.name_filter.as_ref().is_none_or(|filter| filter(&name));
+            if full || !wanted {
+                map.next_value::<IgnoredAny>()?;
+            } else {
+                let host: Host = map.next_value()?;
+                if self.filter.as_ref().is_none_or(|filter| filter(&host)) {
+                    hosts.insert(name, host);
+                }
+            }
+
+            parsed += 1;
+            if parsed % self.chunk_size == 0 {
+                let kept = hosts.len();
+                self.report(LoadProgress {
+                    parsed,
+                    kept,
+                    done: false,
+                });
+            }
+        }
+
+        let kept = hosts.len();
+        self.report(LoadProgress {
+            parsed,
+            kept,
+            done: true,
+        });
+        Ok(hosts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(count: usize) -> String {
+        let entries: Vec<String> = (1..=count)
+            .map(|i| format!(r#""r{i}": {{ "name": "r{i}", "port": {} }}"#, 2200 + i))
+            .collect();
+        format!("{{{}}}", entries.join(","))
+    }
+
+    #[test]
+    fn test_progress_reports_every_chunk() {
+        let mut reports = Vec::new();
+        let hosts = IncrementalLoader::new()
+            .chunk_size(4)
+            .on_progress(|progress| reports.push(progress))
+            .load_json(source(10).as_bytes())
+            .unwrap();
+        assert_eq!(hosts.len(), 10);
+        let parsed: Vec<usize> = reports.iter().map(|p| p.parsed).collect();
+        assert_eq!(parsed, vec![4, 8, 10]);
+        assert!(!reports[0].done);
+        assert!(reports.last().unwrap().done);
+    }
+
+    #[test]
+    fn test_name_filter_and_limit() {
+        let hosts = IncrementalLoader::new()
+            .name_filter(|name| name != "r2")
+            .limit(3)
+            .load_json(source(10).as_bytes())
+            .unwrap();
+        let names: Vec<&str> = hosts.keys_str().collect();
+        assert_eq!(names, vec!["r1", "r3", "r4"]);
+    }
+
+    #[test]
+    fn test_load_yaml() {
+        let yaml = "r1:\n  name: r1\n  platform: eos\nr2:\n  name: r2\n";
+        let hosts = IncrementalLoader::new()
+            .filter(|host| host.platform.is_some())
+            .load_yaml(yaml.as_bytes())
+            .unwrap();
+        assert_eq!(hosts.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_host_is_an_error() {
+        let result = IncrementalLoader::new().load_json(r#"{"r1": {"port": 1}}"#.as_bytes());
+        assert!(result.is_err());
+    }
+}
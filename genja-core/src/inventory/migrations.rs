@@ -0,0 +1,368 @@
+//! Upgrades serialized inventories written for older schema versions.
+//!
+//! Documents carry their version in a top level `schema_version` key.
+//! [`migrate`] takes documents without it for version 1, while inventories
+//! deserialized directly default to [`SCHEMA_VERSION`].
+
+use super::{Inventory, InventoryError};
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// Schema version written by this release.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Version [`migrate`] assumes for documents without `schema_version`.
+pub(crate) const fn legacy_schema_version() -> u32 {
+    1
+}
+
+/// Version of inventories deserialized without `schema_version`.
+pub(crate) const fn current_schema_version() -> u32 {
+    SCHEMA_VERSION
+}
+
+/// Deserializes `schema_version`, rejecting versions newer than
+/// [`SCHEMA_VERSION`] that this release would silently misread.
+pub(crate) fn deserialize_schema_version<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let version = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+    if version > SCHEMA_VERSION {
+        return Err(serde::de::Error::custom(format_args!(
+            "schema_version {version} is newer than the supported version {SCHEMA_VERSION}"
+        )));
+    }
+    Ok(version)
+}
+
+/// Keys of a single, unkeyed `connection_options` object in version 1.
+const CONNECTION_OPTION_FIELDS: &[&str] = &[
+    "plugin", "hostname", "port", "username", "password", "platform", "extras",
+];
+
+/// Options for [`migrate`].
+#[derive(Debug, Clone)]
+pub struct MigrationOptions {
+    /// Plugin a version 1 single `connection_options` object is moved under
+    /// when it has no `plugin` key. Defaults to `ssh`.
+    pub default_plugin: String,
+}
+
+impl Default for MigrationOptions {
+    fn default() -> Self {
+        MigrationOptions {
+            default_plugin: "ssh".to_string(),
+        }
+    }
+}
+
+impl MigrationOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn default_plugin(mut self, plugin: &str) -> Self {
+        self.default_plugin = plugin.to_string();
+        self
+    }
+}
+
+/// A single change made while migrating.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedMigration {
+    /// Version the change upgrades to.
+    pub version: u32,
+    /// Dotted path of the changed value, e.g. `hosts.r1.connection_options`.
+    pub location: String,
+    pub description: String,
+}
+
+impl fmt::Display for AppliedMigration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "v{} {}: {}",
+            self.version, self.location, self.description
+        )
+    }
+}
+
+/// The changes [`migrate`] made to a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub applied: Vec<AppliedMigration>,
+}
+
+impl MigrationReport {
+    /// Returns `true` if the document was already current.
+    pub fn is_empty(&self) -> bool {
+        self.applied.is_empty()
+    }
+}
+
+/// One line per applied change.
+impl fmt::Display for MigrationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "schema version {} -> {}",
+            self.from_version, self.to_version
+        )?;
+        for migration in &self.applied {
+            write!(f, "\n  {migration}")?;
+        }
+        Ok(())
+    }
+}
+
+fn migration_error(location: &str, message: impl Into<String>) -> InventoryError {
+    InventoryError::Migration {
+        location: location.to_string(),
+        message: message.into(),
+    }
+}
+
+/// Upgrades `document` in place to [`SCHEMA_VERSION`].
+///
+/// Version 1 to 2:
+///
+/// * A single `connection_options` object on a host or group is moved
+///   under its `plugin` key, or [`MigrationOptions::default_plugin`].
+/// * `extras` given as a JSON string are parsed into structured values.
+///
+/// Documents newer than this release are rejected.
+pub fn migrate(
+    document: &mut Value,
+    options: &MigrationOptions,
+) -> Result<MigrationReport, InventoryError> {
+    let Value::Object(root) = document else {
+        return Err(migration_error(
+            "inventory",
+            "expected an object at the top level",
+        ));
+    };
+    let from_version = match root.get("schema_version") {
+        None => legacy_schema_version(),
+        Some(version) => version
+            .as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| migration_error("schema_version", "expected an integer"))?,
+    };
+    if from_version > SCHEMA_VERSION {
+        return Err(migration_error(
+            "schema_version",
+            format!("version {from_version} is newer than the supported version {SCHEMA_VERSION}"),
+        ));
+    }
+
+    let mut report = MigrationReport {
+        from_version,
+        to_version: SCHEMA_VERSION,
+        applied: Vec::new(),
+    };
+    if from_version < 2 {
+        v1_to_v2(root, options, &mut report.applied)?;
+    }
+    root.insert("schema_version".to_string(), Value::from(SCHEMA_VERSION));
+    Ok(report)
+}
+
+fn v1_to_v2(
+    root: &mut Map<String, Value>,
+    options: &MigrationOptions,
+    applied: &mut Vec<AppliedMigration>,
+) -> Result<(), InventoryError> {
+    for section in ["hosts", "groups"] {
+        let Some(Value::Object(entries)) = root.get_mut(section) else {
+            continue;
+        };
+        for (name, entry) in entries.iter_mut() {
+            let Some(connection_options) = entry.get_mut("connection_options") else {
+                continue;
+            };
+            let location = format!("{section}.{name}.connection_options");
+            key_by_plugin(connection_options, &location, options, applied);
+            structure_extras(connection_options, &location, applied)?;
+        }
+    }
+    if let Some(connection_options) = root
+        .get_mut("defaults")
+        .and_then(|defaults| defaults.get_mut("connection_options"))
+    {
+        structure_extras(connection_options, "defaults.connection_options", applied)?;
+    }
+    Ok(())
+}
+
+/// Moves a single, unkeyed connection options object under its plugin.
+fn key_by_plugin(
+    connection_options: &mut Value,
+    location: &str,
+    options: &MigrationOptions,
+    applied: &mut Vec<AppliedMigration>,
+) {
+    let Value::Object(map) = connection_options else {
+        return;
+    };
+    let is_single = !map.is_empty()
+        && map.iter().all(|(key, value)| {
+            CONNECTION_OPTION_FIELDS.contains(&key.as_str()) && !is_plugin_entry(key, value)
+        });
+    if !is_single {
+        return;
+    }
+    let mut single = std::mem::take(map);
+    let plugin = match single.remove("plugin") {
+        Some(Value::String(plugin)) => plugin,
+        _ => options.default_plugin.clone(),
+    };
+    map.insert(plugin.clone(), Value::Object(single));
+    applied.push(AppliedMigration {
+        version: 2,
+        location: location.to_string(),
+        description: format!("moved single connection options under plugin `{plugin}`"),
+    });
+}
+
+/// A plugin named like a connection option field, e.g. a plugin called
+/// `platform`, still maps to an object of options.
+fn is_plugin_entry(key: &str, value: &Value) -> bool {
+    key != "extras" && value.is_object()
+}
+
+/// Parses `extras` given as a JSON string in each plugin's options.
+fn structure_extras(
+    connection_options: &mut Value,
+    location: &str,
+    applied: &mut Vec<AppliedMigration>,
+) -> Result<(), InventoryError> {
+    let Value::Object(plugins) = connection_options else {
+        return Ok(());
+    };
+    for (plugin, options) in plugins.iter_mut() {
+        let Some(extras) = options.get_mut("extras") else {
+            continue;
+        };
+        let Value::String(raw) = extras else {
+            continue;
+        };
+        let location = format!("{location}.{plugin}.extras");
+        *extras = serde_json::from_str(raw)
+            .map_err(|error| migration_error(&location, format!("invalid JSON string: {error}")))?;
+        applied.push(AppliedMigration {
+            version: 2,
+            location,
+            description: "parsed string extras into structured extras".to_string(),
+        });
+    }
+    Ok(())
+}
+
+impl Inventory {
+    /// Migrates `document` to the current schema, see [`migrate`], and
+    /// deserializes it.
+    pub fn from_value_migrated(
+        mut document: Value,
+        options: &MigrationOptions,
+    ) -> Result<(Inventory, MigrationReport), InventoryError> {
+        let report = migrate(&mut document, options)?;
+        for migration in &report.applied {
+            log::info!("inventory migration {migration}");
+        }
+        let inventory = serde_json::from_value(document)
+            .map_err(|error| migration_error("inventory", error.to_string()))?;
+        Ok((inventory, report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_migrate_v1_document() {
+        let document = json!({
+            "hosts": {
+                "r1": {
+                    "name": "r1",
+                    "connection_options": { "port": 830, "plugin": "netconf" }
+                },
+                "r2": {
+                    "name": "r2",
+                    "connection_options": { "username": "admin", "extras": "{\"timeout\": 30}" }
+                }
+            },
+            "defaults": {
+                "connection_options": { "http": { "extras": "{\"verify\": false}" } }
+            }
+        });
+        let (inventory, report) =
+            Inventory::from_value_migrated(document, &MigrationOptions::new()).unwrap();
+        assert_eq!((report.from_version, report.to_version), (1, 2));
+        assert_eq!(report.applied.len(), 4);
+        assert_eq!(
+            report.applied[0].to_string(),
+            "v2 hosts.r1.connection_options: moved single connection options under plugin `netconf`"
+        );
+        assert_eq!(inventory.schema_version, SCHEMA_VERSION);
+
+        let r1 = &inventory.hosts["r1"];
+        assert_eq!(
+            r1.connection_options.as_ref().unwrap()["netconf"].port,
+            Some(830)
+        );
+        let r2 = &inventory.hosts["r2"];
+        let ssh = &r2.connection_options.as_ref().unwrap()["ssh"];
+        assert_eq!(**ssh.extras.as_ref().unwrap(), json!({ "timeout": 30 }));
+        let defaults = inventory.defaults.as_ref().unwrap();
+        assert_eq!(
+            defaults["connection_options"]["http"]["extras"],
+            json!({ "verify": false })
+        );
+    }
+
+    #[test]
+    fn test_current_documents_are_untouched() {
+        let mut document = json!({
+            "schema_version": 2,
+            "hosts": { "r1": { "name": "r1", "connection_options": { "ssh": { "port": 22 } } } }
+        });
+        let original = document.clone();
+        let report = migrate(&mut document, &MigrationOptions::new()).unwrap();
+        assert!(report.is_empty());
+        assert_eq!(document, original);
+    }
+
+    #[test]
+    fn test_direct_deserialization_versions() {
+        let inventory: Inventory = serde_json::from_value(json!({ "hosts": {} })).unwrap();
+        assert_eq!(inventory.schema_version, SCHEMA_VERSION);
+        let error =
+            serde_json::from_value::<Inventory>(json!({ "schema_version": 99, "hosts": {} }))
+                .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("newer than the supported version"));
+    }
+
+    #[test]
+    fn test_migration_errors() {
+        let mut newer = json!({ "schema_version": 99, "hosts": {} });
+        assert!(migrate(&mut newer, &MigrationOptions::new())
+            .unwrap_err()
+            .to_string()
+            .contains("newer than the supported version 2"));
+
+        let mut broken = json!({
+            "hosts": { "r1": { "name": "r1", "connection_options": { "ssh": { "extras": "{" } } } }
+        });
+        assert!(migrate(&mut broken, &MigrationOptions::new())
+            .unwrap_err()
+            .to_string()
+            .starts_with("cannot migrate `hosts.r1.connection_options.ssh.extras`"));
+    }
+}
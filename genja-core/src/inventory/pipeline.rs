@@ -0,0 +1,577 @@
+use super::{
+    compose_file, migrate, Inventory, InventoryError, MigrationOptions, MigrationReport,
+    TransformFunction, ValidationProfile,
+};
+use serde_json::Value;
+use std::fmt;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// A stage of [`LoadPipeline`], in the order they run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LoadStage {
+    /// The text is parsed and migrated to the current schema.
+    Parse,
+    /// `${NAME}` and `${NAME:-default}` placeholders are replaced with
+    /// environment variables. `$${` is a literal `${`, e.g. for templates
+    /// kept in host data. Hooks see literal `${` escaped, including in the
+    /// values of environment variables, which are never read as
+    /// placeholders.
+    Interpolate,
+    /// `${secret:REFERENCE}` placeholders are replaced through the secrets
+    /// resolver.
+    Secrets,
+    /// The document is deserialized and the transform function is applied.
+    Transform,
+    /// The inventory is checked against the validation profile.
+    Validate,
+    /// Identical host data is shared, see [`Hosts::share_data`](super::Hosts::share_data).
+    /// Freezing the inventory is left to [`Inventory::freeze`].
+    ShareData,
+}
+
+impl LoadStage {
+    pub const ALL: [LoadStage; 6] = [
+        LoadStage::Parse,
+        LoadStage::Interpolate,
+        LoadStage::Secrets,
+        LoadStage::Transform,
+        LoadStage::Validate,
+        LoadStage::ShareData,
+    ];
+}
+
+impl fmt::Display for LoadStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            LoadStage::Parse => "parse",
+            LoadStage::Interpolate => "interpolate",
+            LoadStage::Secrets => "secrets",
+            LoadStage::Transform => "transform",
+            LoadStage::Validate => "validate",
+            LoadStage::ShareData => "share data",
+        };
+        f.write_str(name)
+    }
+}
+
+/// What a hook sees: the raw document up to [`LoadStage::Secrets`], the
+/// inventory from [`LoadStage::Transform`] on.
+pub enum StageData<'s> {
+    Document(&'s mut Value),
+    Inventory(&'s mut Inventory),
+}
+
+impl StageData<'_> {
+    fn reborrow(&mut self) -> StageData<'_> {
+        match self {
+            StageData::Document(document) => StageData::Document(document),
+            StageData::Inventory(inventory) => StageData::Inventory(inventory),
+        }
+    }
+}
+
+/// Time spent in a stage, hooks included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StageTiming {
+    pub stage: LoadStage,
+    pub elapsed: Duration,
+}
+
+/// Outcome of a successful [`LoadPipeline`] run.
+#[derive(Debug, Clone)]
+pub struct LoadReport {
+    pub migration: MigrationReport,
+    /// One entry per stage, in order.
+    pub timings: Vec<StageTiming>,
+}
+
+impl LoadReport {
+    pub fn elapsed(&self, stage: LoadStage) -> Option<Duration> {
+        self.timings
+            .iter()
+            .find(|timing| timing.stage == stage)
+            .map(|timing| timing.elapsed)
+    }
+
+    pub fn total(&self) -> Duration {
+        self.timings.iter().map(|timing| timing.elapsed).sum()
+    }
+}
+
+type EnvLookup<'a> = Box<dyn Fn(&str) -> Option<String> + 'a>;
+type SecretResolver<'a> = Box<dyn Fn(&str) -> Result<String, String> + 'a>;
+type StageHook<'a> = Box<dyn Fn(StageData<'_>) -> Result<(), String> + 'a>;
+
+/// Loads an inventory document through a fixed sequence of stages:
+/// parse, interpolate, secrets, transform, validate and share data.
+///
+/// Hooks registered with [`LoadPipeline::hook`] run after the built-in work
+/// of their stage and can inspect or change the document or inventory. A
+/// failing stage or hook stops the load with [`InventoryError::Load`].
+///
+/// # Examples
+///
+/// ```
+/// # use genja_core::inventory::{LoadPipeline, LoadStage};
+/// let source = r#"{
+///     "schema_version": 2,
+///     "hosts": { "r1": { "name": "r1", "username": "${USER:-admin}" } }
+/// }"#;
+///
+/// let (inventory, report) = LoadPipeline::new()
+///     .env(|_| None)
+///     .load_str(source)
+///     .unwrap();
+///
+/// assert_eq!(inventory.hosts["r1"].username.as_deref(), Some("admin"));
+/// assert_eq!(report.timings.len(), LoadStage::ALL.len());
+/// ```
+pub struct LoadPipeline<'a> {
+    migration: MigrationOptions,
+    env: EnvLookup<'a>,
+    secrets: Option<SecretResolver<'a>>,
+    transform: Option<TransformFunction>,
+    profile: ValidationProfile,
+    hooks: Vec<(LoadStage, StageHook<'a>)>,
+}
+
+impl Default for LoadPipeline<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> LoadPipeline<'a> {
+    pub fn new() -> Self {
+        LoadPipeline {
+            migration: MigrationOptions::default(),
+            env: Box::new(|name| std::env::var(name).ok()),
+            secrets: None,
+            transform: None,
+            profile: ValidationProfile::default(),
+            hooks: Vec::new(),
+        }
+    }
+
+    pub fn migration(mut self, options: MigrationOptions) -> Self {
+        self.migration = options;
+        self
+    }
+
+    /// Replaces the process environment as the source of `${NAME}` values.
+    pub fn env(mut self, lookup: impl Fn(&str) -> Option<String> + 'a) -> Self {
+        self.env = Box::new(lookup);
+        self
+    }
+
+    /// Sets the resolver for `${secret:REFERENCE}` placeholders. Without one,
+    /// any such placeholder fails the load.
+    pub fn secrets(mut self, resolver: impl Fn(&str) -> Result<String, String> + 'a) -> Self {
+        self.secrets = Some(Box::new(resolver));
+        self
+    }
+
+    /// Sets the transform function, replacing any set on the inventory.
+    pub fn transform(mut self, transform: TransformFunction) -> Self {
+        self.transform = Some(transform);
+        self
+    }
+
+    /// Profile used by the validate stage. Defaults to
+    /// [`ValidationProfile::Standard`].
+    pub fn profile(mut self, profile: ValidationProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Runs `hook` at the end of `stage`. Hooks of the same stage run in
+    /// the order they were added.
+    pub fn hook(
+        mut self,
+        stage: LoadStage,
+        hook: impl Fn(StageData<'_>) -> Result<(), String> + 'a,
+    ) -> Self {
+        self.hooks.push((stage, Box::new(hook)));
+        self
+    }
+
+    /// Runs every stage on a JSON document.
+    pub fn load_str(&self, source: &str) -> Result<(Inventory, LoadReport), InventoryError> {
+        let start = Instant::now();
+        let document = serde_json::from_str(source)
+            .map_err(|error| load_error(LoadStage::Parse, error.to_string()))?;
+        self.run(document, start)
+    }
+
+    /// Runs every stage on a YAML or JSON file, composed with the files it
+    /// extends, see [`compose_file`].
+    pub fn load_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<(Inventory, LoadReport), InventoryError> {
+        let start = Instant::now();
+        let document = compose_file(path)?;
+        self.run(document, start)
+    }
+
+    /// Runs every stage on an already parsed document.
+    pub fn load_value(&self, document: Value) -> Result<(Inventory, LoadReport), InventoryError> {
+        self.run(document, Instant::now())
+    }
+
+    fn run(
+        &self,
+        mut document: Value,
+        start: Instant,
+    ) -> Result<(Inventory, LoadReport), InventoryError> {
+        let mut timings = Vec::with_capacity(LoadStage::ALL.len());
+        let mut timed = |stage: LoadStage, start: Instant| {
+            let elapsed = start.elapsed();
+            log::debug!("inventory load stage {stage} took {elapsed:?}");
+            timings.push(StageTiming { stage, elapsed });
+        };
+
+        let migration = migrate(&mut document, &self.migration)?;
+        self.run_hooks(LoadStage::Parse, StageData::Document(&mut document))?;
+        timed(LoadStage::Parse, start);
+
+        let start = Instant::now();
+        substitute_all(
+            &mut document,
+            LoadStage::Interpolate,
+            false,
+            &mut |placeholder| {
+                if placeholder.starts_with("secret:") {
+                    return None;
+                }
+                let (name, default) = match placeholder.split_once(":-") {
+                    Some((name, default)) => (name, Some(default)),
+                    None => (placeholder, None),
+                };
+                Some(
+                    (self.env)(name)
+                        .or_else(|| default.map(str::to_string))
+                        .ok_or_else(|| format!("environment variable `{name}` is not set")),
+                )
+            },
+        )?;
+        self.run_hooks(LoadStage::Interpolate, StageData::Document(&mut document))?;
+        timed(LoadStage::Interpolate, start);
+
+        let start = Instant::now();
+        substitute_all(
+            &mut document,
+            LoadStage::Secrets,
+            true,
+            &mut |placeholder| {
+                let reference = placeholder.strip_prefix("secret:")?;
+                Some(match &self.secrets {
+                    Some(resolver) => resolver(reference),
+                    None => Err(format!("no secrets resolver for `{reference}`")),
+                })
+            },
+        )?;
+        self.run_hooks(LoadStage::Secrets, StageData::Document(&mut document))?;
+        timed(LoadStage::Secrets, start);
+
+        let start = Instant::now();
+        let mut inventory: Inventory = serde_json::from_value(document)
+            .map_err(|error| load_error(LoadStage::Transform, error.to_string()))?;
+        if let Some(transform) = &self.transform {
+            inventory.transform_function = Some(transform.clone());
+        }
+        if let Some(transform) = inventory.transform_function.clone() {
+            let options = inventory.transform_function_options.clone();
+            transform.call(&mut inventory, options.as_ref());
+        }
+        self.run_hooks(LoadStage::Transform, StageData::Inventory(&mut inventory))?;
+        timed(LoadStage::Transform, start);
+
+        let start = Instant::now();
+        inventory.validate_with(self.profile)?;
+        self.run_hooks(LoadStage::Validate, StageData::Inventory(&mut inventory))?;
+        timed(LoadStage::Validate, start);
+
+        let start = Instant::now();
+        inventory.hosts.share_data();
+        self.run_hooks(LoadStage::ShareData, StageData::Inventory(&mut inventory))?;
+        timed(LoadStage::ShareData, start);
+
+        Ok((inventory, LoadReport { migration, timings }))
+    }
+
+    fn run_hooks(&self, stage: LoadStage, mut data: StageData<'_>) -> Result<(), InventoryError> {
+        for (_, hook) in self.hooks.iter().filter(|(at, _)| *at == stage) {
+            hook(data.reborrow()).map_err(|message| load_error(stage, message))?;
+        }
+        Ok(())
+    }
+}
+
+fn load_error(stage: LoadStage, message: String) -> InventoryError {
+    InventoryError::Load { stage, message }
+}
+
+/// Replaces `${...}` placeholders in every string of `value`. `resolve`
+/// returns `None` for placeholders it leaves in place. Escaped `$${` are
+/// skipped, and turned into `${` when `unescape` is set, which the last
+/// pass does. Until then substituted values are escaped as well, so a later
+/// pass only sees the placeholders of the original text.
+fn substitute_all(
+    value: &mut Value,
+    stage: LoadStage,
+    unescape: bool,
+    resolve: &mut dyn FnMut(&str) -> Option<Result<String, String>>,
+) -> Result<(), InventoryError> {
+    fn walk(
+        value: &mut Value,
+        location: &str,
+        stage: LoadStage,
+        unescape: bool,
+        resolve: &mut dyn FnMut(&str) -> Option<Result<String, String>>,
+    ) -> Result<(), InventoryError> {
+        match value {
+            Value::String(text) => {
+                if let Some(replaced) = substitute(text, unescape, resolve)
+                    .map_err(|message| load_error(stage, format!("`{location}`: {message}")))?
+                {
+                    *text = replaced;
+                }
+            }
+            Value::Array(items) => {
+                for (index, item) in items.iter_mut().enumerate() {
+                    walk(
+                        item,
+                        &format!("{location}[{index}]"),
+                        stage,
+                        unescape,
+                        resolve,
+                    )?;
+                }
+            }
+            Value::Object(map) => {
+                for (key, item) in map.iter_mut() {
+                    let location = if location.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{location}.{key}")
+                    };
+                    walk(item, &location, stage, unescape, resolve)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+    walk(value, "", stage, unescape, resolve)
+}
+
+/// Returns `None` when `text` is unchanged.
+fn substitute(
+    text: &str,
+    unescape: bool,
+    resolve: &mut dyn FnMut(&str) -> Option<Result<String, String>>,
+) -> Result<Option<String>, String> {
+    if !text.contains("${") {
+        return Ok(None);
+    }
+    let mut output = String::with_capacity(text.len());
+    // Set while `output` ends with a `$` of a substituted value.
+    let mut dangling = false;
+    let mut rest = text;
+    while let Some(open) = rest.find("${") {
+        if rest[..open].ends_with('$') {
+            push(&mut output, &rest[..open - 1], &mut dangling);
+            push(
+                &mut output,
+                if unescape { "${" } else { "$${" },
+                &mut dangling,
+            );
+            rest = &rest[open + 2..];
+            continue;
+        }
+        let Some(close) = rest[open..].find('}') else {
+            break;
+        };
+        push(&mut output, &rest[..open], &mut dangling);
+        let placeholder = &rest[open + 2..open + close];
+        match resolve(placeholder) {
+            Some(value) if unescape => push(&mut output, &value?, &mut dangling),
+            Some(value) => {
+                let value = value?.replace("${", "$${");
+                push(&mut output, &value, &mut dangling);
+                dangling = dangling || value.ends_with('$');
+            }
+            None => push(&mut output, &rest[open..=open + close], &mut dangling),
+        }
+        rest = &rest[open + close + 1..];
+    }
+    push(&mut output, rest, &mut dangling);
+    Ok(Some(output))
+}
+
+/// Appends `piece` to `output`. A `$` that a substituted value left at the
+/// end of `output` is escaped when `piece` starts with `{`, so the two
+/// never form a placeholder.
+fn push(output: &mut String, piece: &str, dangling: &mut bool) {
+    if piece.is_empty() {
+        return;
+    }
+    if *dangling && piece.starts_with('{') {
+        output.push('$');
+    }
+    *dangling = false;
+    output.push_str(piece);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    const SOURCE: &str = r#"{
+        "schema_version": 2,
+        "hosts": {
+            "r1": {
+                "name": "r1",
+                "hostname": "${SITE}-r1.example.com",
+                "username": "${USERNAME:-admin}",
+                "password": "${secret:vault/r1}"
+            }
+        },
+        "transform_function_options": { "platform": "eos" }
+    }"#;
+
+    fn env(name: &str) -> Option<String> {
+        (name == "SITE").then(|| "hq".to_string())
+    }
+
+    #[test]
+    fn test_pipeline_runs_every_stage() {
+        let seen = RefCell::new(Vec::new());
+        let pipeline = LoadPipeline::new()
+            .env(env)
+            .secrets(|reference| Ok(format!("<{reference}>")))
+            .transform(TransformFunction::new(|inventory, options| {
+                let platform = options.and_then(|options| options["platform"].as_str());
+                for host in inventory.hosts.values_mut() {
+                    host.platform = platform.map(str::to_string);
+                }
+            }));
+        let pipeline = LoadStage::ALL.iter().fold(pipeline, |pipeline, stage| {
+            let seen = &seen;
+            pipeline.hook(*stage, move |data| {
+                let kind = match data {
+                    StageData::Document(_) => "document",
+                    StageData::Inventory(_) => "inventory",
+                };
+                seen.borrow_mut().push(format!("{stage}:{kind}"));
+                Ok(())
+            })
+        });
+
+        let (inventory, report) = pipeline.load_str(SOURCE).unwrap();
+        let r1 = &inventory.hosts["r1"];
+        assert_eq!(r1.hostname.as_deref(), Some("hq-r1.example.com"));
+        assert_eq!(r1.username.as_deref(), Some("admin"));
+        assert_eq!(r1.password.as_deref(), Some("<vault/r1>"));
+        assert_eq!(r1.platform.as_deref(), Some("eos"));
+        assert!(report.migration.is_empty());
+        let stages: Vec<LoadStage> = report.timings.iter().map(|timing| timing.stage).collect();
+        assert_eq!(stages, LoadStage::ALL);
+        assert!(report.elapsed(LoadStage::ShareData).is_some());
+        drop(pipeline);
+        assert_eq!(
+            seen.into_inner(),
+            [
+                "parse:document",
+                "interpolate:document",
+                "secrets:document",
+                "transform:inventory",
+                "validate:inventory",
+                "share data:inventory",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pipeline_errors_name_the_stage() {
+        let error = LoadPipeline::new().env(env).load_str(SOURCE).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "inventory load failed at the secrets stage: `hosts.r1.password`: no secrets resolver for `vault/r1`"
+        );
+
+        let error = LoadPipeline::new()
+            .env(|_| None)
+            .load_str(SOURCE)
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .ends_with("`hosts.r1.hostname`: environment variable `SITE` is not set"));
+
+        let error = LoadPipeline::new()
+            .env(env)
+            .secrets(|_| Ok(String::new()))
+            .hook(LoadStage::Validate, |_| Err("rejected".to_string()))
+            .load_str(SOURCE)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "inventory load failed at the validate stage: rejected"
+        );
+    }
+
+    #[test]
+    fn test_escaped_placeholders_are_literal() {
+        let source = r#"{
+            "schema_version": 2,
+            "hosts": {
+                "r1": {
+                    "name": "r1",
+                    "username": "${USERNAME:-admin}",
+                    "data": { "banner": "Welcome to $${hostname}, $${secret:x} ${SITE}" }
+                }
+            }
+        }"#;
+        let (inventory, _) = LoadPipeline::new().env(env).load_str(source).unwrap();
+        let r1 = &inventory.hosts["r1"];
+        assert_eq!(r1.username.as_deref(), Some("admin"));
+        assert_eq!(
+            r1.data.as_ref().unwrap()["banner"],
+            "Welcome to ${hostname}, ${secret:x} hq"
+        );
+    }
+
+    #[test]
+    fn test_substituted_values_are_not_placeholders() {
+        let source = r#"{
+            "schema_version": 2,
+            "hosts": {
+                "r1": {
+                    "name": "r1",
+                    "username": "${USERNAME}",
+                    "data": { "note": "${DOLLAR}{secret:x} $${SITE}" }
+                }
+            }
+        }"#;
+        let lookups = RefCell::new(Vec::new());
+        let (inventory, _) = LoadPipeline::new()
+            .env(|name| match name {
+                "USERNAME" => Some("${secret:vault/admin}".to_string()),
+                "DOLLAR" => Some("$".to_string()),
+                _ => None,
+            })
+            .secrets(|reference| {
+                lookups.borrow_mut().push(reference.to_string());
+                Ok(String::new())
+            })
+            .load_str(source)
+            .unwrap();
+        let r1 = &inventory.hosts["r1"];
+        assert_eq!(r1.username.as_deref(), Some("${secret:vault/admin}"));
+        assert_eq!(r1.data.as_ref().unwrap()["note"], "${secret:x} ${SITE}");
+        assert!(lookups.into_inner().is_empty());
+    }
+}
@@ -0,0 +1,225 @@
+use super::{Inventory, InventoryError, LoadPipeline, SimpleInventory};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// A source of inventories, registered by name in an
+/// [`InventoryPluginRegistry`].
+///
+/// Closures of the same signature are plugins too.
+pub trait InventoryPlugin: Send + Sync {
+    fn load(&self, options: &Value) -> Result<Inventory, InventoryError>;
+}
+
+impl<F> InventoryPlugin for F
+where
+    F: Fn(&Value) -> Result<Inventory, InventoryError> + Send + Sync,
+{
+    fn load(&self, options: &Value) -> Result<Inventory, InventoryError> {
+        self(options)
+    }
+}
+
+/// Deserializes plugin `options`, `null` being the default options.
+pub fn plugin_options<T: DeserializeOwned + Default>(
+    plugin: &str,
+    options: &Value,
+) -> Result<T, InventoryError> {
+    if options.is_null() {
+        return Ok(T::default());
+    }
+    T::deserialize(options).map_err(|error| InventoryError::Plugin {
+        name: plugin.to_string(),
+        message: format!("invalid options: {error}"),
+    })
+}
+
+/// Options of the built-in `simple` plugin, named as in Python Nornir.
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct SimpleOptions {
+    host_file: String,
+    group_file: String,
+    defaults_file: String,
+}
+
+impl Default for SimpleOptions {
+    fn default() -> Self {
+        SimpleOptions {
+            host_file: "hosts.yaml".to_string(),
+            group_file: "groups.yaml".to_string(),
+            defaults_file: "defaults.yaml".to_string(),
+        }
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileOptions {
+    path: String,
+}
+
+/// Inventory plugins by name.
+///
+/// [`InventoryPluginRegistry::new`] comes with two plugins:
+///
+/// * `simple` - [`SimpleInventory`] with the options `host_file`,
+///   `group_file` and `defaults_file`.
+/// * `file` - a YAML or JSON inventory loaded through [`LoadPipeline`]
+///   with the option `path`.
+///
+/// Plugins registered with [`register_inventory_plugin`] are available to
+/// [`Genja::with_inventory_plugin`](crate::Genja::with_inventory_plugin).
+#[derive(Clone)]
+pub struct InventoryPluginRegistry {
+    plugins: BTreeMap<String, Arc<dyn InventoryPlugin>>,
+}
+
+impl Default for InventoryPluginRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for InventoryPluginRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.plugins.keys()).finish()
+    }
+}
+
+impl InventoryPluginRegistry {
+    /// A registry with the built-in plugins.
+    pub fn new() -> Self {
+        let mut registry = InventoryPluginRegistry {
+            plugins: BTreeMap::new(),
+        };
+        registry.register("simple", |options: &Value| {
+            let options: SimpleOptions = plugin_options("simple", options)?;
+            SimpleInventory::load(options.host_file, options.group_file, options.defaults_file)
+        });
+        registry.register("file", |options: &Value| {
+            let options: FileOptions = plugin_options("file", options)?;
+            LoadPipeline::new()
+                .load_file(options.path)
+                .map(|(inventory, _)| inventory)
+        });
+        registry
+    }
+
+    /// Registers `plugin` as `name`, returning the plugin it replaces.
+    pub fn register(
+        &mut self,
+        name: &str,
+        plugin: impl InventoryPlugin + 'static,
+    ) -> Option<Arc<dyn InventoryPlugin>> {
+        self.plugins.insert(name.to_string(), Arc::new(plugin))
+    }
+
+    pub fn get(&self, name: &str) -> Option<Arc<dyn InventoryPlugin>> {
+        self.plugins.get(name).cloned()
+    }
+
+    /// Names of the registered plugins, in order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.plugins.keys().map(String::as_str)
+    }
+
+    /// Loads an inventory with the plugin registered as `name`.
+    pub fn load(&self, name: &str, options: &Value) -> Result<Inventory, InventoryError> {
+        let plugin = self.get(name).ok_or_else(|| InventoryError::Plugin {
+            name: name.to_string(),
+            message: format!(
+                "not registered, available plugins: {}",
+                self.names().collect::<Vec<_>>().join(", ")
+            ),
+        })?;
+        plugin.load(options)
+    }
+}
+
+fn global() -> &'static RwLock<InventoryPluginRegistry> {
+    static REGISTRY: OnceLock<RwLock<InventoryPluginRegistry>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(InventoryPluginRegistry::new()))
+}
+
+/// Registers `plugin` as `name` in the process wide registry, returning the
+/// plugin it replaces.
+pub fn register_inventory_plugin(
+    name: &str,
+    plugin: impl InventoryPlugin + 'static,
+) -> Option<Arc<dyn InventoryPlugin>> {
+    global()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .register(name, plugin)
+}
+
+/// Loads an inventory with a plugin of the process wide registry.
+pub fn load_inventory_plugin(name: &str, options: &Value) -> Result<Inventory, InventoryError> {
+    let registry = global().read().unwrap_or_else(|e| e.into_inner());
+    match registry.get(name) {
+        Some(plugin) => {
+            // The plugin runs without the lock held so it can register plugins.
+            drop(registry);
+            plugin.load(options)
+        }
+        None => registry.load(name, options),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::Host;
+    use serde_json::json;
+
+    struct StaticPlugin;
+
+    impl InventoryPlugin for StaticPlugin {
+        fn load(&self, options: &Value) -> Result<Inventory, InventoryError> {
+            let mut inventory = Inventory::new();
+            for name in options["hosts"].as_array().into_iter().flatten() {
+                inventory
+                    .hosts
+                    .add_host(Host::new(name.as_str().unwrap_or("")));
+            }
+            Ok(inventory)
+        }
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = InventoryPluginRegistry::new();
+        assert!(registry.register("static", StaticPlugin).is_none());
+        assert_eq!(
+            registry.names().collect::<Vec<_>>(),
+            ["file", "simple", "static"]
+        );
+
+        let inventory = registry
+            .load("static", &json!({ "hosts": ["r1", "r2"] }))
+            .unwrap();
+        assert_eq!(inventory.hosts.len(), 2);
+
+        let error = registry.load("netbox", &Value::Null).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "inventory plugin `netbox`: not registered, available plugins: file, simple, static"
+        );
+        let error = registry
+            .load("simple", &json!({ "hosts": "hosts.yaml" }))
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("invalid options: unknown field `hosts`"));
+    }
+
+    #[test]
+    fn test_global_registry() {
+        register_inventory_plugin("empty", |_: &Value| Ok(Inventory::new()));
+        assert!(load_inventory_plugin("empty", &Value::Null).is_ok());
+        assert!(load_inventory_plugin("missing", &Value::Null).is_err());
+    }
+}
@@ -0,0 +1,163 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Outbound proxies used to reach a host, set as
+/// `connection_options.<plugin>.proxy` or as `proxy` at the top level of the
+/// defaults to apply to every plugin.
+///
+/// HTTP based plugins use `http`/`https` depending on the URL scheme, SSH
+/// based plugins tunnel through `socks5`. Unlike [`TlsConfig`](super::TlsConfig)
+/// the proxy settings are not merged: the first layer that sets `proxy` wins.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct ProxyConfig {
+    /// Proxy URL for `http://` requests, e.g. `http://proxy:3128`.
+    pub http: Option<String>,
+    /// Proxy URL for `https://` requests.
+    pub https: Option<String>,
+    /// SOCKS5 proxy for raw TCP transports, e.g. `socks5://jump:1080`.
+    pub socks5: Option<String>,
+    /// Hosts reached directly, matched like `NO_PROXY`: an exact name or
+    /// address, a domain suffix such as `.lab.example.com`, or `*`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub no_proxy: Vec<String>,
+}
+
+impl ProxyConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn http(mut self, url: &str) -> Self {
+        self.http = Some(url.to_string());
+        self
+    }
+
+    pub fn https(mut self, url: &str) -> Self {
+        self.https = Some(url.to_string());
+        self
+    }
+
+    pub fn socks5(mut self, url: &str) -> Self {
+        self.socks5 = Some(url.to_string());
+        self
+    }
+
+    pub fn no_proxy(mut self, pattern: &str) -> Self {
+        self.no_proxy.push(pattern.to_string());
+        self
+    }
+
+    /// Returns `true` if `hostname` is excluded by `no_proxy`.
+    pub fn bypasses(&self, hostname: &str) -> bool {
+        let hostname = hostname.trim_end_matches('.').to_ascii_lowercase();
+        self.no_proxy.iter().any(|pattern| {
+            let pattern = pattern.trim().trim_end_matches('.').to_ascii_lowercase();
+            if pattern == "*" {
+                return true;
+            }
+            let domain = pattern.trim_start_matches('.');
+            !domain.is_empty()
+                && (hostname == domain
+                    || hostname
+                        .strip_suffix(domain)
+                        .is_some_and(|rest| rest.ends_with('.')))
+        })
+    }
+
+    /// The proxy for an HTTP request to `hostname` with `scheme` (`http` or
+    /// `https`), or `None` to connect directly.
+    pub fn http_proxy_for(&self, scheme: &str, hostname: &str) -> Option<&str> {
+        if self.bypasses(hostname) {
+            return None;
+        }
+        match scheme {
+            "https" => self.https.as_deref(),
+            "http" => self.http.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The SOCKS5 proxy for a TCP connection to `hostname`, or `None` to
+    /// connect directly.
+    pub fn socks5_for(&self, hostname: &str) -> Option<&str> {
+        if self.bypasses(hostname) {
+            return None;
+        }
+        self.socks5.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::{Inventory, ParamSource};
+    use serde_json::json;
+
+    #[test]
+    fn test_no_proxy_matching() {
+        let proxy = ProxyConfig::new()
+            .https("http://proxy:3128")
+            .socks5("socks5://jump:1080")
+            .no_proxy(".lab.example.com")
+            .no_proxy("10.0.0.1");
+
+        assert_eq!(
+            proxy.socks5_for("r1.dc1.example.com"),
+            Some("socks5://jump:1080")
+        );
+        assert_eq!(proxy.socks5_for("R1.Lab.Example.com"), None);
+        assert_eq!(proxy.socks5_for("lab.example.com"), None);
+        assert_eq!(
+            proxy.socks5_for("notlab.example.com"),
+            Some("socks5://jump:1080")
+        );
+        assert_eq!(proxy.socks5_for("10.0.0.1"), None);
+        assert_eq!(
+            proxy.http_proxy_for("https", "api.example.com"),
+            Some("http://proxy:3128")
+        );
+        assert_eq!(proxy.http_proxy_for("http", "api.example.com"), None);
+        assert!(ProxyConfig::new().no_proxy("*").bypasses("anything"));
+    }
+
+    #[test]
+    fn test_proxy_resolution() {
+        let inventory: Inventory = serde_json::from_value(json!({
+            "hosts": {
+                "r1": { "name": "r1", "groups": ["dmz"] },
+                "r2": { "name": "r2" }
+            },
+            "groups": {
+                "dmz": {
+                    "connection_options": { "ssh": { "proxy": { "socks5": "socks5://dmz-jump:1080" } } }
+                }
+            },
+            "defaults": {
+                "proxy": { "socks5": "socks5://jump:1080", "https": "http://proxy:3128" }
+            }
+        }))
+        .unwrap();
+
+        let r1 = inventory.hosts.get("r1").unwrap();
+        let (params, provenance) = inventory.explain_connection_params(r1, "ssh");
+        assert_eq!(
+            params.proxy,
+            Some(ProxyConfig::new().socks5("socks5://dmz-jump:1080"))
+        );
+        assert_eq!(
+            provenance.proxy,
+            Some(ParamSource::GroupConnectionOptions("dmz".to_string()))
+        );
+
+        let r2 = inventory.hosts.get("r2").unwrap();
+        let (params, provenance) = inventory.explain_connection_params(r2, "http");
+        assert_eq!(
+            params.proxy.unwrap().http_proxy_for("https", "r2"),
+            Some("http://proxy:3128")
+        );
+        assert_eq!(provenance.proxy, Some(ParamSource::Defaults));
+    }
+}
@@ -0,0 +1,131 @@
+use super::{Host, Inventory, InventoryError};
+use jmespath::{Expression, Variable};
+use serde::Serialize;
+use serde_json::Value;
+
+pub(crate) fn compile_query(expression: &str) -> Result<Expression<'static>, InventoryError> {
+    jmespath::compile(expression).map_err(|err| InventoryError::Query {
+        expression: expression.to_string(),
+        message: err.to_string(),
+    })
+}
+
+fn search<T: Serialize>(
+    expr: &Expression<'static>,
+    expression: &str,
+    data: &T,
+) -> Result<jmespath::Rcvar, InventoryError> {
+    let query_error = |message: String| InventoryError::Query {
+        expression: expression.to_string(),
+        message,
+    };
+    let data = Variable::from_serializable(data).map_err(|err| query_error(err.to_string()))?;
+    expr.search(data)
+        .map_err(|err| query_error(err.to_string()))
+}
+
+/// Returns `true` when `expr` evaluates to a truthy value for `host`.
+pub(crate) fn query_matches(
+    expr: &Expression<'static>,
+    expression: &str,
+    host: &Host,
+) -> Result<bool, InventoryError> {
+    Ok(search(expr, expression, host)?.is_truthy())
+}
+
+impl Inventory {
+    /// Evaluates a JMESPath expression against the inventory.
+    ///
+    /// The expression is evaluated against a document of the form
+    /// `{"hosts": [<host>, ...], "groups": {<name>: <group>}, "defaults": {...}}`
+    /// where hosts are listed in natural name order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use genja_core::inventory::{BaseBuilderHost, Host, Hosts, Inventory};
+    /// # use serde_json::json;
+    /// let mut hosts = Hosts::new();
+    /// hosts.add_host(Host::builder("r1").data_json(json!({ "role": "core_router" })).build());
+    /// hosts.add_host(Host::builder("s1").data_json(json!({ "role": "access" })).build());
+    /// let inventory = Inventory::builder().hosts(hosts).build();
+    ///
+    /// let names = inventory
+    ///     .query("hosts[?data.role=='core_router'].name")
+    ///     .unwrap();
+    /// assert_eq!(names, json!(["r1"]));
+    /// ```
+    pub fn query(&self, expression: &str) -> Result<Value, InventoryError> {
+        #[derive(Serialize)]
+        struct Document<'a> {
+            hosts: Vec<&'a Host>,
+            groups: &'a Option<super::Groups>,
+            defaults: &'a Option<super::Defaults>,
+        }
+
+        let expr = compile_query(expression)?;
+        let document = Document {
+            hosts: self.hosts.values().collect(),
+            groups: &self.groups,
+            defaults: &self.defaults,
+        };
+        let result = search(&expr, expression, &document)?;
+        serde_json::to_value(&*result).map_err(|err| InventoryError::Query {
+            expression: expression.to_string(),
+            message: err.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::{BaseBuilderHost, Hosts};
+    use crate::Genja;
+    use serde_json::json;
+
+    fn inventory() -> Inventory {
+        let mut hosts = Hosts::new();
+        for (name, role, platform) in [
+            ("r10", "core_router", "eos"),
+            ("r2", "core_router", "ios"),
+            ("s1", "access", "eos"),
+        ] {
+            hosts.add_host(
+                Host::builder(name)
+                    .platform(platform)
+                    .data_json(json!({ "role": role }))
+                    .build(),
+            );
+        }
+        Inventory::builder().hosts(hosts).build()
+    }
+
+    #[test]
+    fn test_query_returns_json() {
+        let inventory = inventory();
+        assert_eq!(
+            inventory
+                .query("hosts[?data.role=='core_router'].name")
+                .unwrap(),
+            json!(["r2", "r10"])
+        );
+        assert_eq!(inventory.query("length(hosts)").unwrap(), json!(3));
+    }
+
+    #[test]
+    fn test_invalid_query_is_an_error() {
+        let err = inventory().query("hosts[?").unwrap_err();
+        assert!(matches!(err, InventoryError::Query { .. }));
+    }
+
+    #[test]
+    fn test_genja_filter_query() {
+        let genja = Genja::new(inventory());
+        let filtered = genja
+            .filter_query("platform == 'eos' && data.role == 'core_router'")
+            .unwrap();
+        let names: Vec<&str> = filtered.iter_hosts().map(|h| h.name.as_str()).collect();
+        assert_eq!(names, vec!["r10"]);
+    }
+}
@@ -0,0 +1,184 @@
+use super::{Host, Hosts};
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// Keys accepted by `Host` deserialization, including serde aliases.
+const HOST_FIELDS: &[&str] = &[
+    "name",
+    "hostname",
+    "ip",
+    "port",
+    "username",
+    "user",
+    "password",
+    "platform",
+    "groups",
+    "data",
+    "connection_options",
+    "defaults",
+];
+
+/// A key that relaxed deserialization did not recognise as a `Host` field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownFieldWarning {
+    pub host: String,
+    pub field: String,
+    /// `true` when the key was moved into `Data`, `false` when it was dropped
+    /// because `Data` already contained the same key or is not an object.
+    pub moved_to_data: bool,
+}
+
+impl fmt::Display for UnknownFieldWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.moved_to_data {
+            write!(
+                f,
+                "host `{}`: unknown field `{}` moved into data",
+                self.host, self.field
+            )
+        } else {
+            write!(
+                f,
+                "host `{}`: unknown field `{}` dropped, it conflicts with data",
+                self.host, self.field
+            )
+        }
+    }
+}
+
+impl Host {
+    /// Deserializes a host without rejecting unknown fields.
+    ///
+    /// `Host` normally uses `deny_unknown_fields`. In relaxed mode any key that
+    /// is not a host field is moved into the host's `Data` and reported in the
+    /// returned warning list, which keeps inventories written for Nornir-Python
+    /// loadable. `name` is used when the object has no `name` key of its own.
+    pub fn from_value_relaxed(
+        name: &str,
+        value: Value,
+    ) -> Result<(Host, Vec<UnknownFieldWarning>), serde_json::Error> {
+        let Value::Object(mut object) = value else {
+            return serde_json::from_value(value).map(|host| (host, Vec::new()));
+        };
+        object
+            .entry("name")
+            .or_insert_with(|| Value::String(name.to_string()));
+        let host_name = object
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or(name)
+            .to_string();
+
+        let unknown: Vec<String> = object
+            .keys()
+            .filter(|key| !HOST_FIELDS.contains(&key.as_str()))
+            .cloned()
+            .collect();
+
+        let mut warnings = Vec::new();
+        if !unknown.is_empty() {
+            // Non-object data cannot absorb extra keys, so they are dropped.
+            let mut data = match object.remove("data") {
+                Some(Value::Object(data)) => Some(data),
+                Some(Value::Null) | None => Some(Map::new()),
+                Some(other) => {
+                    object.insert("data".to_string(), other);
+                    None
+                }
+            };
+            for field in unknown {
+                let value = object.remove(&field).unwrap_or(Value::Null);
+                let moved_to_data = match data.as_mut() {
+                    Some(data) if !data.contains_key(&field) => {
+                        data.insert(field.clone(), value);
+                        true
+                    }
+                    _ => false,
+                };
+                warnings.push(UnknownFieldWarning {
+                    host: host_name.clone(),
+                    field,
+                    moved_to_data,
+                });
+            }
+            if let Some(data) = data {
+                object.insert("data".to_string(), Value::Object(data));
+            }
+        }
+
+        for warning in &warnings {
+            log::warn!("{warning}");
+        }
+        let host = serde_json::from_value(Value::Object(object))?;
+        Ok((host, warnings))
+    }
+}
+
+impl Hosts {
+    /// Deserializes a map of host name to host object in relaxed mode.
+    ///
+    /// See [`Host::from_value_relaxed`].
+    pub fn from_value_relaxed(
+        value: Value,
+    ) -> Result<(Hosts, Vec<UnknownFieldWarning>), serde_json::Error> {
+        let Value::Object(entries) = value else {
+            return serde_json::from_value(value).map(|hosts| (hosts, Vec::new()));
+        };
+        let mut hosts = Hosts::new();
+        let mut warnings = Vec::new();
+        for (name, value) in entries {
+            let (host, host_warnings) = Host::from_value_relaxed(&name, value)?;
+            warnings.extend(host_warnings);
+            hosts.insert(name, host);
+        }
+        Ok((hosts, warnings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_strict_mode_accepts_aliases() {
+        let host: Host =
+            serde_json::from_value(json!({ "name": "r1", "ip": "10.0.0.1", "user": "admin" }))
+                .unwrap();
+        assert_eq!(host.hostname.as_deref(), Some("10.0.0.1"));
+        assert_eq!(host.username.as_deref(), Some("admin"));
+    }
+
+    #[test]
+    fn test_relaxed_mode_moves_unknown_fields_into_data() {
+        let (hosts, warnings) = Hosts::from_value_relaxed(json!({
+            "r1": {
+                "hostname": "10.0.0.1",
+                "site": "hq",
+                "rack": 4,
+                "data": { "role": "core", "rack": 7 }
+            }
+        }))
+        .unwrap();
+
+        let host = hosts.get("r1").unwrap();
+        assert_eq!(host.name, "r1");
+        assert_eq!(
+            **host.data.as_ref().unwrap(),
+            json!({ "role": "core", "rack": 7, "site": "hq" })
+        );
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings
+            .iter()
+            .any(|w| w.field == "rack" && !w.moved_to_data));
+        assert!(warnings
+            .iter()
+            .any(|w| w.field == "site" && w.moved_to_data));
+    }
+
+    #[test]
+    fn test_strict_mode_still_rejects_unknown_fields() {
+        let result: Result<Host, _> = serde_json::from_value(json!({ "name": "r1", "site": "hq" }));
+        assert!(result.is_err());
+    }
+}
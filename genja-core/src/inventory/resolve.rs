@@ -0,0 +1,492 @@
+use super::{
+    contexts, ConnectionOptions, Defaults, Group, Host, Inventory, ResolvedConnectionParams,
+    TlsConfig,
+};
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+
+/// Order in which a host's groups and their parent groups are visited.
+///
+/// The resulting chain lists groups from highest to lowest precedence. In
+/// both strategies the groups a host lists first win over later ones.
+///
+/// With a host in `[a, b]`, `a` in `[a1]` and `b` in `[b1]`:
+///
+/// * `depth_first` (default) yields `a, a1, b, b1`, so everything inherited
+///   through `a` wins over `b`.
+/// * `breadth_first` yields `a, b, a1, b1`, so the host's direct groups win
+///   over any parent group.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum GroupResolution {
+    #[default]
+    DepthFirst,
+    BreadthFirst,
+}
+
+/// A host together with its groups in resolution order.
+#[derive(Debug, Clone)]
+pub struct ResolvedHost<'a> {
+    inventory: &'a Inventory,
+    host: &'a Host,
+    group_chain: Vec<String>,
+}
+
+impl<'a> ResolvedHost<'a> {
+    pub fn host(&self) -> &'a Host {
+        self.host
+    }
+
+    /// Group names from highest to lowest precedence, each listed once.
+    ///
+    /// Groups referenced by a host or group but missing from the inventory
+    /// are still part of the chain.
+    pub fn group_chain(&self) -> &[String] {
+        &self.group_chain
+    }
+
+    /// Resolves the connection parameters of this host for `plugin`, see
+    /// [`Inventory::resolve_connection_params`].
+    pub fn connection_params(&self, plugin: &str) -> ResolvedConnectionParams {
+        self.explain_connection_params(plugin).0
+    }
+
+    /// Like [`ResolvedHost::connection_params`], also returning where each
+    /// field was taken from.
+    pub fn explain_connection_params(
+        &self,
+        plugin: &str,
+    ) -> (ResolvedConnectionParams, ParamProvenance) {
+        if let Some(resolved) = contexts::explain_context_params(self.inventory, self.host, plugin)
+        {
+            return resolved;
+        }
+        let mut option_layers = Vec::new();
+        let mut attribute_layers = Vec::new();
+
+        option_layers.push((
+            ParamSource::HostConnectionOptions,
+            plugin_options(self.host.connection_options.as_ref(), plugin),
+        ));
+        attribute_layers.push((
+            ParamSource::Host,
+            Some(attributes(
+                &self.host.hostname,
+                self.host.port,
+                &self.host.username,
+                &self.host.password,
+                &self.host.platform,
+            )),
+        ));
+        for (name, group) in self.groups() {
+            option_layers.push((
+                ParamSource::GroupConnectionOptions(name.to_string()),
+                plugin_options(group.connection_options.as_ref(), plugin),
+            ));
+            attribute_layers.push((
+                ParamSource::Group(name.to_string()),
+                Some(attributes(
+                    &group.hostname,
+                    group.port,
+                    &group.username,
+                    &group.password,
+                    &group.platform,
+                )),
+            ));
+        }
+        let defaults = self
+            .host
+            .defaults
+            .as_deref()
+            .or(self.inventory.defaults.as_ref());
+        if let Some(defaults) = defaults {
+            option_layers.push((
+                ParamSource::DefaultsConnectionOptions,
+                defaults_options(defaults, Some(plugin)),
+            ));
+            attribute_layers.push((ParamSource::Defaults, defaults_options(defaults, None)));
+        }
+
+        let layers: Vec<(ParamSource, ConnectionOptions)> = option_layers
+            .into_iter()
+            .chain(attribute_layers)
+            .filter_map(|(source, options)| options.map(|options| (source, options)))
+            .collect();
+
+        macro_rules! pick {
+            ($field:ident) => {
+                layers
+                    .iter()
+                    .find_map(|(source, options)| {
+                        options.$field.clone().map(|value| (value, source.clone()))
+                    })
+                    .unzip()
+            };
+        }
+        let (hostname, hostname_source) = pick!(hostname);
+        let (port, port_source) = pick!(port);
+        let (username, username_source) = pick!(username);
+        let (password, password_source) = pick!(password);
+        let (platform, platform_source) = pick!(platform);
+        let (extras, extras_source) = pick!(extras);
+        let (credentials, credentials_source) = pick!(credentials);
+        let (proxy, proxy_source) = pick!(proxy);
+        let tls_source = layers
+            .iter()
+            .find_map(|(source, options)| options.tls.as_ref().map(|_| source.clone()));
+        let tls = layers
+            .iter()
+            .filter_map(|(_, options)| options.tls.as_ref())
+            .fold(None, |tls: Option<TlsConfig>, lower| {
+                Some(tls.map_or_else(|| lower.clone(), |tls| tls.or(lower)))
+            });
+
+        let params = ResolvedConnectionParams {
+            hostname: hostname.unwrap_or_else(|| self.host.name.clone()),
+            port,
+            username,
+            password,
+            platform,
+            extras,
+            credentials: credentials.unwrap_or_default(),
+            tls,
+            proxy,
+        };
+        let provenance = ParamProvenance {
+            hostname: hostname_source.unwrap_or(ParamSource::HostName),
+            port: port_source,
+            username: username_source,
+            password: password_source,
+            platform: platform_source,
+            extras: extras_source,
+            credentials: credentials_source,
+            tls: tls_source,
+            proxy: proxy_source,
+        };
+        (params, provenance)
+    }
+
+    /// The groups of the chain that exist in the inventory, in chain order.
+    pub fn groups(&self) -> impl Iterator<Item = (&str, &'a Group)> + '_ {
+        let groups = self.inventory.groups.as_ref();
+        self.group_chain.iter().filter_map(move |name| {
+            groups
+                .and_then(|groups| groups.get(name))
+                .map(|group| (name.as_str(), group))
+        })
+    }
+}
+
+/// Where a resolved connection parameter was taken from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamSource {
+    /// The host's `connection_options` for the plugin.
+    HostConnectionOptions,
+    /// The host's own field.
+    Host,
+    /// The `connection_options` for the plugin of the named group.
+    GroupConnectionOptions(String),
+    /// The named group's own field.
+    Group(String),
+    /// `connection_options.<plugin>` in the defaults.
+    DefaultsConnectionOptions,
+    /// A top level field of the defaults.
+    Defaults,
+    /// No hostname is set anywhere, the host name is used.
+    HostName,
+    /// Resolved for the named parent of a device context, see
+    /// [`DeviceContext`](super::DeviceContext).
+    Parent(String),
+}
+
+impl fmt::Display for ParamSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamSource::HostConnectionOptions => f.write_str("host connection_options"),
+            ParamSource::Host => f.write_str("host"),
+            ParamSource::GroupConnectionOptions(name) => {
+                write!(f, "group `{name}` connection_options")
+            }
+            ParamSource::Group(name) => write!(f, "group `{name}`"),
+            ParamSource::DefaultsConnectionOptions => f.write_str("defaults connection_options"),
+            ParamSource::Defaults => f.write_str("defaults"),
+            ParamSource::HostName => f.write_str("host name"),
+            ParamSource::Parent(name) => write!(f, "parent `{name}`"),
+        }
+    }
+}
+
+/// The source of each field of a [`ResolvedConnectionParams`]. `None` means
+/// the field is not set anywhere.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamProvenance {
+    pub hostname: ParamSource,
+    pub port: Option<ParamSource>,
+    pub username: Option<ParamSource>,
+    pub password: Option<ParamSource>,
+    pub platform: Option<ParamSource>,
+    pub extras: Option<ParamSource>,
+    pub credentials: Option<ParamSource>,
+    /// The layer with the highest precedence setting `tls`. Unset TLS
+    /// fields are filled from lower layers.
+    pub tls: Option<ParamSource>,
+    pub proxy: Option<ParamSource>,
+}
+
+fn plugin_options(
+    options: Option<&crate::CustomTreeMap<ConnectionOptions>>,
+    plugin: &str,
+) -> Option<ConnectionOptions> {
+    options.and_then(|options| options.get(plugin)).cloned()
+}
+
+fn attributes(
+    hostname: &Option<String>,
+    port: Option<u16>,
+    username: &Option<String>,
+    password: &Option<String>,
+    platform: &Option<String>,
+) -> ConnectionOptions {
+    ConnectionOptions {
+        hostname: hostname.clone(),
+        port,
+        username: username.clone(),
+        password: password.clone(),
+        platform: platform.clone(),
+        ..ConnectionOptions::new()
+    }
+}
+
+/// Reads connection fields from the free-form defaults: the top level for
+/// `plugin == None`, `connection_options.<plugin>` otherwise.
+fn defaults_options(defaults: &Defaults, plugin: Option<&str>) -> Option<ConnectionOptions> {
+    let value = match plugin {
+        Some(plugin) => defaults.get("connection_options")?.get(plugin)?,
+        None => &**defaults,
+    };
+    let mut options = match ConnectionOptions::deserialize(value) {
+        Ok(options) => options,
+        Err(error) => {
+            log::warn!("ignoring invalid connection fields in defaults: {error}");
+            return None;
+        }
+    };
+    if plugin.is_none() {
+        // Only plugin specific sections carry extras, credentials and TLS.
+        // A top level `proxy` applies to every plugin.
+        options.extras = None;
+        options.credentials = None;
+        options.tls = None;
+    }
+    Some(options)
+}
+
+impl Inventory {
+    /// Resolves the parameters used to open a `plugin` connection to `host`
+    /// without modifying the host or opening anything.
+    ///
+    /// Each field is taken from the first layer that sets it, in this order:
+    ///
+    /// 1. `connection_options.<plugin>` of the host, of each group in
+    ///    [`ResolvedHost::group_chain`] order, then of the defaults.
+    /// 2. The plain fields of the host, of each group, then of the defaults.
+    ///
+    /// The hostname falls back to the host name and `tls` is merged field by
+    /// field across layers, see [`TlsConfig`]. Use
+    /// [`Inventory::explain_connection_params`] to see which layer each
+    /// field came from.
+    pub fn resolve_connection_params(&self, host: &Host, plugin: &str) -> ResolvedConnectionParams {
+        self.explain_connection_params(host, plugin).0
+    }
+
+    /// Like [`Inventory::resolve_connection_params`], also returning the
+    /// source of each field.
+    pub fn explain_connection_params(
+        &self,
+        host: &Host,
+        plugin: &str,
+    ) -> (ResolvedConnectionParams, ParamProvenance) {
+        ResolvedHost {
+            inventory: self,
+            host,
+            group_chain: self.group_chain(host, self.group_resolution),
+        }
+        .explain_connection_params(plugin)
+    }
+
+    fn parent_groups(&self, name: &str) -> &[String] {
+        self.groups
+            .as_ref()
+            .and_then(|groups| groups.get(name))
+            .and_then(|group| group.groups.as_ref())
+            .map(|parents| parents.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Returns the host's groups and all of their ancestors in the order
+    /// given by `strategy`. Cycles between groups are visited once.
+    pub fn group_chain(&self, host: &Host, strategy: GroupResolution) -> Vec<String> {
+        let direct = host
+            .groups
+            .as_ref()
+            .map(|g| g.as_slice())
+            .unwrap_or_default();
+        let mut seen = HashSet::new();
+        let mut chain = Vec::new();
+
+        match strategy {
+            GroupResolution::DepthFirst => {
+                let mut stack: Vec<&str> = direct.iter().rev().map(String::as_str).collect();
+                while let Some(name) = stack.pop() {
+                    if !seen.insert(name) {
+                        continue;
+                    }
+                    chain.push(name.to_string());
+                    stack.extend(self.parent_groups(name).iter().rev().map(String::as_str));
+                }
+            }
+            GroupResolution::BreadthFirst => {
+                let mut queue: VecDeque<&str> = direct.iter().map(String::as_str).collect();
+                while let Some(name) = queue.pop_front() {
+                    if !seen.insert(name) {
+                        continue;
+                    }
+                    chain.push(name.to_string());
+                    queue.extend(self.parent_groups(name).iter().map(String::as_str));
+                }
+            }
+        }
+        chain
+    }
+
+    /// Looks up a host and resolves its group chain with the inventory's
+    /// `group_resolution` strategy.
+    pub fn resolve_host(&self, name: &str) -> Option<ResolvedHost<'_>> {
+        let host = self.hosts.get(name)?;
+        Some(ResolvedHost {
+            inventory: self,
+            host,
+            group_chain: self.group_chain(host, self.group_resolution),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::{BaseBuilderHost, Groups, Hosts, ParentGroups};
+    use crate::CustomTreeMap;
+    use serde_json::json;
+
+    fn inventory(strategy: &str) -> Inventory {
+        serde_json::from_value(json!({
+            "hosts": { "r1": { "name": "r1", "groups": ["a", "b"] } },
+            "groups": {
+                "a": { "groups": ["a1"] },
+                "a1": { "groups": ["global"] },
+                "b": { "groups": ["b1", "a"] },
+                "b1": {},
+                "global": { "groups": ["a"] }
+            },
+            "group_resolution": strategy
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_depth_first_chain() {
+        let inventory = inventory("depth_first");
+        let resolved = inventory.resolve_host("r1").unwrap();
+        assert_eq!(resolved.group_chain(), ["a", "a1", "global", "b", "b1"]);
+    }
+
+    #[test]
+    fn test_breadth_first_chain() {
+        let inventory = inventory("breadth_first");
+        let resolved = inventory.resolve_host("r1").unwrap();
+        assert_eq!(resolved.group_chain(), ["a", "b", "a1", "b1", "global"]);
+    }
+
+    #[test]
+    fn test_connection_params_provenance() {
+        let inventory: Inventory = serde_json::from_value(json!({
+            "hosts": {
+                "r1": {
+                    "name": "r1",
+                    "hostname": "10.0.0.1",
+                    "groups": ["edge", "core"],
+                    "connection_options": { "netconf": { "port": 830 } }
+                }
+            },
+            "groups": {
+                "edge": { "platform": "junos" },
+                "core": {
+                    "port": 22,
+                    "platform": "eos",
+                    "connection_options": { "netconf": { "username": "netconf" } }
+                }
+            },
+            "defaults": {
+                "username": "admin",
+                "password": "secret",
+                "connection_options": { "netconf": { "extras": { "timeout": 30 } } }
+            }
+        }))
+        .unwrap();
+        let host = inventory.hosts.get("r1").unwrap();
+
+        let (params, provenance) = inventory.explain_connection_params(host, "netconf");
+        assert_eq!(params.hostname, "10.0.0.1");
+        assert_eq!(params.port, Some(830));
+        assert_eq!(params.username.as_deref(), Some("netconf"));
+        assert_eq!(params.password.as_deref(), Some("secret"));
+        assert_eq!(params.platform.as_deref(), Some("junos"));
+        assert_eq!(*params.extras.unwrap(), json!({ "timeout": 30 }));
+
+        assert_eq!(provenance.hostname, ParamSource::Host);
+        assert_eq!(provenance.port, Some(ParamSource::HostConnectionOptions));
+        assert_eq!(
+            provenance.username.unwrap().to_string(),
+            "group `core` connection_options"
+        );
+        assert_eq!(provenance.password, Some(ParamSource::Defaults));
+        assert_eq!(provenance.platform, Some(ParamSource::Group("edge".into())));
+        assert_eq!(
+            provenance.extras,
+            Some(ParamSource::DefaultsConnectionOptions)
+        );
+
+        let ssh = inventory.resolve_connection_params(host, "ssh");
+        assert_eq!(ssh.port, Some(22));
+        assert_eq!(ssh.username.as_deref(), Some("admin"));
+        assert!(host.resolved_connection_params.is_empty());
+    }
+
+    #[test]
+    fn test_missing_groups_stay_in_chain() {
+        let mut hosts = Hosts::new();
+        hosts.add_host(Host::builder("r1").group("edge").group("core").build());
+        let mut groups = CustomTreeMap::new();
+        groups.insert(
+            "core",
+            Group {
+                groups: Some(ParentGroups(vec!["dc1".to_string()])),
+                ..Group::new()
+            },
+        );
+        let inventory = Inventory::builder()
+            .hosts(hosts)
+            .groups(Groups(groups))
+            .build();
+
+        let resolved = inventory.resolve_host("r1").unwrap();
+        assert_eq!(resolved.group_chain(), ["edge", "core", "dc1"]);
+        let existing: Vec<&str> = resolved.groups().map(|(name, _)| name).collect();
+        assert_eq!(existing, ["core"]);
+        assert!(inventory.resolve_host("r2").is_none());
+    }
+}
@@ -0,0 +1,350 @@
+use super::{Group, Host, Inventory};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Data key holding a group's scheduling hints.
+const SCHEDULING_KEY: &str = "scheduling";
+
+/// Limits on how hosts of a group are worked on, read from
+/// `data.scheduling` of the group, e.g. at most 2 hosts per POP at once:
+///
+/// ```yaml
+/// groups:
+///   pop-ams:
+///     data:
+///       scheduling: { max_concurrent: 2, min_delay: 30, window: "22:00-04:00" }
+/// ```
+///
+/// Hints apply to every host of the group, including hosts of its child
+/// groups, and are enforced through [`GroupLimiter`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SchedulingHints {
+    /// Maximum number of hosts of the group worked on at the same time.
+    pub max_concurrent: Option<usize>,
+    /// Minimum number of seconds between starting two hosts of the group.
+    pub min_delay: Option<f64>,
+    /// Time of day, in UTC, during which hosts of the group may be worked on.
+    pub window: Option<TimeWindow>,
+}
+
+impl SchedulingHints {
+    pub fn min_delay(&self) -> Option<Duration> {
+        self.min_delay
+            .filter(|delay| delay.is_finite() && *delay > 0.0)
+            .map(Duration::from_secs_f64)
+    }
+}
+
+/// A daily time window in UTC, written `HH:MM-HH:MM`. A window whose end
+/// is before its start spans midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeWindow {
+    /// Minutes after midnight.
+    pub start: u16,
+    /// Minutes after midnight, exclusive.
+    pub end: u16,
+}
+
+impl TimeWindow {
+    /// Returns `true` if `minute`, in minutes after midnight, is inside the
+    /// window.
+    pub fn contains(&self, minute: u16) -> bool {
+        if self.start <= self.end {
+            (self.start..self.end).contains(&minute)
+        } else {
+            minute >= self.start || minute < self.end
+        }
+    }
+
+    pub fn is_open_at(&self, time: SystemTime) -> bool {
+        let seconds = time
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        self.contains(((seconds % 86_400) / 60) as u16)
+    }
+}
+
+impl FromStr for TimeWindow {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let minute = |time: &str| {
+            let (hours, minutes) = time.trim().split_once(':')?;
+            let (hours, minutes) = (hours.parse::<u16>().ok()?, minutes.parse::<u16>().ok()?);
+            (hours < 24 && minutes < 60).then_some(hours * 60 + minutes)
+        };
+        s.split_once('-')
+            .and_then(|(start, end)| Some((minute(start)?, minute(end)?)))
+            .map(|(start, end)| TimeWindow { start, end })
+            .ok_or_else(|| format!("invalid time window `{s}`, expected HH:MM-HH:MM"))
+    }
+}
+
+impl fmt::Display for TimeWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}-{:02}:{:02}",
+            self.start / 60,
+            self.start % 60,
+            self.end / 60,
+            self.end % 60
+        )
+    }
+}
+
+impl Serialize for TimeWindow {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeWindow {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl Group {
+    /// Returns the group's scheduling hints, if any.
+    ///
+    /// Invalid hints are logged and ignored.
+    pub fn scheduling(&self) -> Option<SchedulingHints> {
+        let value = self.data.as_ref()?.get(SCHEDULING_KEY)?;
+        match SchedulingHints::deserialize(value) {
+            Ok(hints) => Some(hints),
+            Err(error) => {
+                log::warn!("ignoring invalid scheduling hints: {error}");
+                None
+            }
+        }
+    }
+}
+
+impl Inventory {
+    /// The scheduling hints applying to `host`, by group, in the order of
+    /// [`Inventory::group_chain`].
+    pub fn scheduling_hints(&self, host: &Host) -> Vec<(String, SchedulingHints)> {
+        let Some(groups) = &self.groups else {
+            return Vec::new();
+        };
+        self.group_chain(host, self.group_resolution)
+            .into_iter()
+            .filter_map(|name| {
+                let hints = groups.get(&name)?.scheduling()?;
+                Some((name, hints))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Default)]
+struct GroupState {
+    running: usize,
+    last_start: Option<Instant>,
+}
+
+/// Enforces the [`SchedulingHints`] of an inventory's groups across
+/// threads.
+///
+/// Workers call [`GroupLimiter::acquire`] before working on a host and keep
+/// the returned permit until they are done: the limits only cover the work
+/// done while the permit is held.
+#[derive(Debug)]
+pub struct GroupLimiter<'a> {
+    inventory: &'a Inventory,
+    hints: BTreeMap<String, SchedulingHints>,
+    state: Mutex<BTreeMap<String, GroupState>>,
+    changed: Condvar,
+}
+
+/// Held while a host is worked on, see [`GroupLimiter::acquire`].
+#[must_use = "the host counts as running until the permit is dropped"]
+#[derive(Debug)]
+pub struct GroupPermit<'l, 'a> {
+    limiter: &'l GroupLimiter<'a>,
+    groups: Vec<String>,
+}
+
+impl<'a> GroupLimiter<'a> {
+    pub fn new(inventory: &'a Inventory) -> Self {
+        let hints = inventory
+            .groups
+            .iter()
+            .flat_map(|groups| groups.iter_str())
+            .filter_map(|(name, group)| Some((name.to_string(), group.scheduling()?)))
+            .collect();
+        GroupLimiter {
+            inventory,
+            hints,
+            state: Mutex::new(BTreeMap::new()),
+            changed: Condvar::new(),
+        }
+    }
+
+    /// Returns `true` if no group declares scheduling hints.
+    pub fn is_empty(&self) -> bool {
+        self.hints.is_empty()
+    }
+
+    fn limited_groups(&self, host: &Host) -> Vec<String> {
+        if self.hints.is_empty() {
+            return Vec::new();
+        }
+        self.inventory
+            .group_chain(host, self.inventory.group_resolution)
+            .into_iter()
+            .filter(|group| self.hints.contains_key(group))
+            .collect()
+    }
+
+    /// Returns the first group of `host` whose window is closed at `time`.
+    pub fn closed_window(&self, host: &Host, time: SystemTime) -> Option<String> {
+        self.limited_groups(host).into_iter().find(|group| {
+            self.hints[group]
+                .window
+                .is_some_and(|window| !window.is_open_at(time))
+        })
+    }
+
+    /// Blocks until `host` can be started without exceeding the
+    /// `max_concurrent` of its groups or starting sooner than their
+    /// `min_delay`.
+    pub fn acquire(&self, host: &Host) -> GroupPermit<'_, 'a> {
+        let groups = self.limited_groups(host);
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        loop {
+            let now = Instant::now();
+            let mut full = false;
+            let mut wait = Duration::ZERO;
+            for group in &groups {
+                let hints = &self.hints[group];
+                let group = state.entry(group.clone()).or_default();
+                full |= hints
+                    .max_concurrent
+                    .is_some_and(|max| group.running >= max.max(1));
+                if let (Some(delay), Some(last_start)) = (hints.min_delay(), group.last_start) {
+                    wait = wait.max((last_start + delay).saturating_duration_since(now));
+                }
+            }
+            if !full && wait.is_zero() {
+                for group in &groups {
+                    let group = state.get_mut(group).expect("state was created above");
+                    group.running += 1;
+                    group.last_start = Some(now);
+                }
+                return GroupPermit {
+                    limiter: self,
+                    groups,
+                };
+            }
+            state = if wait.is_zero() {
+                self.changed.wait(state).unwrap_or_else(|e| e.into_inner())
+            } else {
+                self.changed
+                    .wait_timeout(state, wait)
+                    .unwrap_or_else(|e| e.into_inner())
+                    .0
+            };
+        }
+    }
+}
+
+impl Drop for GroupPermit<'_, '_> {
+    fn drop(&mut self) {
+        if self.groups.is_empty() {
+            return;
+        }
+        let mut state = self.limiter.state.lock().unwrap_or_else(|e| e.into_inner());
+        for group in &self.groups {
+            if let Some(group) = state.get_mut(group) {
+                group.running -= 1;
+            }
+        }
+        self.limiter.changed.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    fn inventory(scheduling: serde_json::Value) -> Inventory {
+        let hosts: serde_json::Map<String, serde_json::Value> = (1..=6)
+            .map(|index| {
+                let name = format!("r{index}");
+                (name.clone(), json!({ "name": name, "groups": ["ams"] }))
+            })
+            .collect();
+        serde_json::from_value(json!({
+            "hosts": hosts,
+            "groups": {
+                "ams": { "groups": ["pops"] },
+                "pops": { "data": { "scheduling": scheduling } }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_time_windows() {
+        let night: TimeWindow = "22:00-04:30".parse().unwrap();
+        assert_eq!(night.to_string(), "22:00-04:30");
+        assert!(night.contains(23 * 60));
+        assert!(night.contains(60));
+        assert!(!night.contains(4 * 60 + 30));
+        assert!(!night.contains(12 * 60));
+        assert!("25:00-01:00".parse::<TimeWindow>().is_err());
+
+        let inventory = inventory(json!({ "window": "01:00-02:00" }));
+        let limiter = GroupLimiter::new(&inventory);
+        let r1 = inventory.hosts.get("r1").unwrap();
+        let noon = UNIX_EPOCH + Duration::from_secs(12 * 3600);
+        assert_eq!(limiter.closed_window(r1, noon), Some("pops".to_string()));
+        let night = UNIX_EPOCH + Duration::from_secs(3600 + 60);
+        assert_eq!(limiter.closed_window(r1, night), None);
+    }
+
+    #[test]
+    fn test_max_concurrent_per_group() {
+        let inventory = inventory(json!({ "max_concurrent": 2 }));
+        let r1 = inventory.hosts.get("r1").unwrap();
+        assert_eq!(inventory.scheduling_hints(r1)[0].0, "pops");
+
+        let limiter = GroupLimiter::new(&inventory);
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            for host in inventory.hosts.values() {
+                scope.spawn(|| {
+                    let _permit = limiter.acquire(host);
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    thread::sleep(Duration::from_millis(20));
+                    running.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+        });
+        assert_eq!(peak.into_inner(), 2);
+    }
+
+    #[test]
+    fn test_min_delay_between_hosts() {
+        let inventory = inventory(json!({ "min_delay": 0.05 }));
+        let limiter = GroupLimiter::new(&inventory);
+        let start = Instant::now();
+        drop(limiter.acquire(inventory.hosts.get("r1").unwrap()));
+        drop(limiter.acquire(inventory.hosts.get("r2").unwrap()));
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+}
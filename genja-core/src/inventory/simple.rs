@@ -0,0 +1,337 @@
+use super::duplicates::merge_json;
+use super::{Data, Defaults, Group, Groups, Host, Inventory, InventoryError, LoadStage};
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::Path;
+
+/// Loads the `hosts.yaml`, `groups.yaml` and `defaults.yaml` files of
+/// Python Nornir's `SimpleInventory` plugin.
+///
+/// The files are used unchanged: host entries are keyed by name without a
+/// `name` field, and empty entries such as `r1:` are accepted.
+///
+/// # Examples
+///
+/// ```
+/// # use genja_core::inventory::SimpleInventory;
+/// let hosts = "
+/// r1:
+///   hostname: 10.0.0.1
+///   groups: [core]
+/// r2:
+/// ";
+/// let groups = "core: { platform: eos }";
+///
+/// let inventory = SimpleInventory::from_yaml(hosts, Some(groups), None).unwrap();
+/// assert_eq!(inventory.hosts.len(), 2);
+/// assert_eq!(inventory.hosts["r1"].hostname.as_deref(), Some("10.0.0.1"));
+/// ```
+pub struct SimpleInventory;
+
+fn simple_error(source: &str, message: impl std::fmt::Display) -> InventoryError {
+    InventoryError::Load {
+        stage: LoadStage::Parse,
+        message: format!("`{source}`: {message}"),
+    }
+}
+
+/// Parses a YAML mapping; an empty file is an empty mapping.
+fn parse_map(source: &str, text: &str) -> Result<Map<String, Value>, InventoryError> {
+    match serde_yaml::from_str(text).map_err(|error| simple_error(source, error))? {
+        Value::Null => Ok(Map::new()),
+        Value::Object(map) => Ok(map),
+        other => Err(simple_error(
+            source,
+            format!("expected a mapping, found {other}"),
+        )),
+    }
+}
+
+/// Replaces empty entries with empty objects.
+fn entry(value: Value) -> Value {
+    match value {
+        Value::Null => Value::Object(Map::new()),
+        value => value,
+    }
+}
+
+/// Reads the `<name>.yaml` and `<name>.yml` files of a `host_vars` or
+/// `group_vars` directory, sorted by name. A missing directory has no vars.
+fn read_vars(dir: &Path) -> Result<Vec<(String, Value)>, InventoryError> {
+    let source = |path: &Path| path.display().to_string();
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(simple_error(&source(dir), error)),
+    };
+    let mut vars = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|error| simple_error(&source(dir), error))?
+            .path();
+        let is_yaml = matches!(
+            path.extension().and_then(|extension| extension.to_str()),
+            Some("yaml" | "yml")
+        );
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        if !is_yaml || !path.is_file() {
+            continue;
+        }
+        let text =
+            fs::read_to_string(&path).map_err(|error| simple_error(&source(&path), error))?;
+        vars.push((
+            name.to_string(),
+            Value::Object(parse_map(&source(&path), &text)?),
+        ));
+    }
+    vars.sort_by(|(a, _), (b, _)| natord::compare(a, b));
+    Ok(vars)
+}
+
+fn merge_data(data: &mut Option<Data>, vars: Value) {
+    match data {
+        Some(data) => merge_json(data, vars),
+        None => *data = Some(Data::new(vars)),
+    }
+}
+
+impl SimpleInventory {
+    /// Loads the three files. `groups_path` and `defaults_path` may be
+    /// missing, as with Python Nornir, in which case they are empty.
+    pub fn load(
+        hosts_path: impl AsRef<Path>,
+        groups_path: impl AsRef<Path>,
+        defaults_path: impl AsRef<Path>,
+    ) -> Result<Inventory, InventoryError> {
+        let read = |path: &Path, required: bool| -> Result<Option<String>, InventoryError> {
+            match fs::read_to_string(path) {
+                Ok(text) => Ok(Some(text)),
+                Err(error) if !required && error.kind() == std::io::ErrorKind::NotFound => {
+                    log::debug!("{} not found, using no entries", path.display());
+                    Ok(None)
+                }
+                Err(error) => Err(simple_error(&path.display().to_string(), error)),
+            }
+        };
+        let hosts = read(hosts_path.as_ref(), true)?.unwrap_or_default();
+        let groups = read(groups_path.as_ref(), false)?;
+        let defaults = read(defaults_path.as_ref(), false)?;
+        Self::from_yaml(&hosts, groups.as_deref(), defaults.as_deref())
+    }
+
+    /// Loads an inventory directory laid out like
+    ///
+    /// ```text
+    /// inventory/
+    ///   hosts.yaml
+    ///   groups.yaml          (optional)
+    ///   defaults.yaml        (optional)
+    ///   host_vars/r1.yaml
+    ///   group_vars/core.yaml
+    ///   group_vars/all.yaml
+    /// ```
+    ///
+    /// Each `host_vars/<host>.yaml` (or `.yml`) is merged into the data of
+    /// that host, and each `group_vars/<group>.yaml` into the data of that
+    /// group, creating groups not listed in `groups.yaml`.
+    /// `group_vars/all.yaml` is merged into the data of the defaults.
+    /// Values from these files win over data set inline, objects being
+    /// merged key by key.
+    pub fn load_dir(dir: impl AsRef<Path>) -> Result<Inventory, InventoryError> {
+        let dir = dir.as_ref();
+        let mut inventory = Self::load(
+            dir.join("hosts.yaml"),
+            dir.join("groups.yaml"),
+            dir.join("defaults.yaml"),
+        )?;
+
+        for (name, vars) in read_vars(&dir.join("host_vars"))? {
+            let host = inventory.hosts.get_mut(&name).ok_or_else(|| {
+                simple_error(&format!("host_vars/{name}"), "no such host in hosts.yaml")
+            })?;
+            merge_data(&mut host.data, vars);
+        }
+
+        for (name, vars) in read_vars(&dir.join("group_vars"))? {
+            if name == "all" {
+                let defaults = inventory
+                    .defaults
+                    .get_or_insert_with(|| Defaults::new(Value::Object(Map::new())));
+                merge_json(defaults, serde_json::json!({ "data": vars }));
+                continue;
+            }
+            let groups = inventory
+                .groups
+                .get_or_insert_with(|| Groups(Default::default()));
+            if groups.get(&name).is_none() {
+                groups.insert(name.clone(), Group::new());
+            }
+            let group = groups.get_mut(&name).expect("group was inserted above");
+            merge_data(&mut group.data, vars);
+        }
+        Ok(inventory)
+    }
+
+    /// Builds the inventory from the contents of the three files.
+    pub fn from_yaml(
+        hosts: &str,
+        groups: Option<&str>,
+        defaults: Option<&str>,
+    ) -> Result<Inventory, InventoryError> {
+        let mut inventory = Inventory::new();
+        for (name, value) in parse_map("hosts", hosts)? {
+            let mut value = entry(value);
+            if let Value::Object(fields) = &mut value {
+                fields.insert("name".to_string(), Value::String(name.clone()));
+            }
+            let host: Host = serde_json::from_value(value)
+                .map_err(|error| simple_error(&format!("hosts.{name}"), error))?;
+            inventory.hosts.add_host(host);
+        }
+
+        if let Some(groups) = groups {
+            let groups: Map<String, Value> = parse_map("groups", groups)?
+                .into_iter()
+                .map(|(name, value)| (name, entry(value)))
+                .collect();
+            if !groups.is_empty() {
+                let groups: Groups = serde_json::from_value(Value::Object(groups))
+                    .map_err(|error| simple_error("groups", error))?;
+                inventory.groups = Some(groups);
+            }
+        }
+
+        if let Some(defaults) = defaults {
+            let defaults = parse_map("defaults", defaults)?;
+            if !defaults.is_empty() {
+                inventory.defaults = Some(Defaults::new(Value::Object(defaults)));
+            }
+        }
+        Ok(inventory)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::TempDir;
+
+    #[test]
+    fn test_nornir_layout() {
+        let hosts = "
+r1:
+  hostname: 10.0.0.1
+  port: 22
+  groups:
+    - core
+  data:
+    site: ams
+  connection_options:
+    netmiko:
+      extras:
+        device_type: arista_eos
+r2: {}
+";
+        let groups = "
+core:
+  platform: eos
+  groups: [global]
+global:
+";
+        let defaults = "
+username: admin
+data:
+  ntp: 10.0.0.100
+";
+        let inventory = SimpleInventory::from_yaml(hosts, Some(groups), Some(defaults)).unwrap();
+        let r1 = &inventory.hosts["r1"];
+        assert_eq!(r1.name, "r1");
+        assert_eq!(r1.port, Some(22));
+        assert_eq!(r1.data.as_ref().unwrap()["site"], "ams");
+        let netmiko = &r1.connection_options.as_ref().unwrap()["netmiko"];
+        assert_eq!(
+            netmiko.extras.as_ref().unwrap()["device_type"],
+            "arista_eos"
+        );
+        assert_eq!(inventory.groups.as_ref().unwrap().len(), 2);
+        assert_eq!(inventory.defaults.as_ref().unwrap()["username"], "admin");
+
+        let params = inventory.resolve_connection_params(r1, "netmiko");
+        assert_eq!(params.username.as_deref(), Some("admin"));
+        assert_eq!(params.platform.as_deref(), Some("eos"));
+    }
+
+    #[test]
+    fn test_missing_optional_files() {
+        let dir = TempDir::new("simple");
+        dir.write("hosts.yaml", "r1:\n");
+        let inventory = SimpleInventory::load(
+            dir.join("hosts.yaml"),
+            dir.join("groups.yaml"),
+            dir.join("defaults.yaml"),
+        );
+        let missing_hosts = SimpleInventory::load(
+            dir.join("missing.yaml"),
+            dir.join("groups.yaml"),
+            dir.join("defaults.yaml"),
+        );
+
+        assert_eq!(inventory.unwrap().hosts.len(), 1);
+        assert!(missing_hosts.is_err());
+
+        let error = SimpleInventory::from_yaml("r1: { hostname: [1] }", None, None).unwrap_err();
+        assert!(error.to_string().contains("`hosts.r1`: invalid type"));
+    }
+
+    #[test]
+    fn test_load_dir_merges_vars() {
+        let dir = TempDir::new("simple-dir");
+        let files = [
+            (
+                "hosts.yaml",
+                "r1:\n  groups: [core]\n  data: { site: ams, snmp: { v: 2 } }\nr2:\n",
+            ),
+            ("groups.yaml", "core: { platform: eos }\n"),
+            ("host_vars/r1.yaml", "snmp: { community: lab }\nrack: A1\n"),
+            ("host_vars/README.md", "not vars"),
+            ("group_vars/core.yml", "ntp: [10.0.0.1]\n"),
+            ("group_vars/edge.yaml", "bgp_asn: 65001\n"),
+            ("group_vars/all.yaml", "dns: 10.0.0.53\n"),
+        ];
+        for (name, content) in files {
+            dir.write(name, content);
+        }
+        let inventory = SimpleInventory::load_dir(dir.path());
+        dir.write("host_vars/r9.yaml", "{}");
+        let unknown_host = SimpleInventory::load_dir(dir.path());
+
+        let inventory = inventory.unwrap();
+        assert_eq!(
+            **inventory.hosts["r1"].data.as_ref().unwrap(),
+            serde_json::json!({ "site": "ams", "snmp": { "v": 2, "community": "lab" }, "rack": "A1" })
+        );
+        assert!(inventory.hosts["r2"].data.is_none());
+        let groups = inventory.groups.as_ref().unwrap();
+        assert_eq!(groups.get("core").unwrap().platform.as_deref(), Some("eos"));
+        assert_eq!(
+            groups.get("core").unwrap().data.as_ref().unwrap()["ntp"][0],
+            "10.0.0.1"
+        );
+        assert_eq!(
+            groups.get("edge").unwrap().data.as_ref().unwrap()["bgp_asn"],
+            65001
+        );
+        assert_eq!(
+            inventory.defaults.as_ref().unwrap()["data"]["dns"],
+            "10.0.0.53"
+        );
+
+        let error = unknown_host.unwrap_err().to_string();
+        assert!(
+            error.ends_with("`host_vars/r9`: no such host in hosts.yaml"),
+            "{error}"
+        );
+    }
+}
@@ -0,0 +1,131 @@
+#[cfg(feature = "schema")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// TLS settings shared by API based connection plugins (HTTP, gNMI,
+/// RESTCONF, ...), set as `connection_options.<plugin>.tls`.
+///
+/// Settings are resolved field by field, so a CA bundle set in a group or
+/// the defaults still applies to hosts that only set a client certificate.
+/// Paths are passed to the plugin as written.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct TlsConfig {
+    /// PEM bundle of CAs trusted in addition to, or instead of, the system
+    /// roots, depending on the plugin.
+    pub ca_bundle: Option<String>,
+    /// PEM client certificate for mutual TLS.
+    pub client_cert: Option<String>,
+    /// PEM private key of `client_cert`.
+    pub client_key: Option<String>,
+    /// Whether the server certificate is verified. Defaults to `true`.
+    pub verify: Option<bool>,
+    /// Server name sent in SNI and checked against the certificate, when it
+    /// differs from the hostname, e.g. when connecting by address.
+    pub server_name: Option<String>,
+}
+
+impl TlsConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ca_bundle(mut self, path: &str) -> Self {
+        self.ca_bundle = Some(path.to_string());
+        self
+    }
+
+    pub fn client_identity(mut self, cert: &str, key: &str) -> Self {
+        self.client_cert = Some(cert.to_string());
+        self.client_key = Some(key.to_string());
+        self
+    }
+
+    pub fn verify(mut self, verify: bool) -> Self {
+        self.verify = Some(verify);
+        self
+    }
+
+    pub fn server_name(mut self, name: &str) -> Self {
+        self.server_name = Some(name.to_string());
+        self
+    }
+
+    /// Whether the server certificate should be verified.
+    pub fn verifies(&self) -> bool {
+        self.verify.unwrap_or(true)
+    }
+
+    /// The server name to use for SNI and verification of `hostname`.
+    pub fn server_name_for<'a>(&'a self, hostname: &'a str) -> &'a str {
+        self.server_name.as_deref().unwrap_or(hostname)
+    }
+
+    /// Fills the fields unset in `self` from `lower`, a layer with lower
+    /// precedence. The client certificate and key are taken as a pair so a
+    /// certificate is never combined with another layer's key.
+    pub(crate) fn or(mut self, lower: &TlsConfig) -> TlsConfig {
+        if self.client_cert.is_none() && self.client_key.is_none() {
+            self.client_cert = lower.client_cert.clone();
+            self.client_key = lower.client_key.clone();
+        }
+        TlsConfig {
+            ca_bundle: self.ca_bundle.or_else(|| lower.ca_bundle.clone()),
+            client_cert: self.client_cert,
+            client_key: self.client_key,
+            verify: self.verify.or(lower.verify),
+            server_name: self.server_name.or_else(|| lower.server_name.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inventory::{Inventory, ParamSource};
+    use serde_json::json;
+
+    #[test]
+    fn test_tls_is_merged_across_layers() {
+        let inventory: Inventory = serde_json::from_value(json!({
+            "hosts": {
+                "r1": {
+                    "name": "r1",
+                    "groups": ["core"],
+                    "connection_options": { "gnmi": { "port": 6030, "tls": {
+                        "client_cert": "r1.crt", "client_key": "r1.key"
+                    } } }
+                }
+            },
+            "groups": {
+                "core": {
+                    "connection_options": { "gnmi": { "tls": {
+                        "verify": false, "client_cert": "core.crt", "client_key": "core.key"
+                    } } }
+                }
+            },
+            "defaults": {
+                "connection_options": { "gnmi": { "tls": { "ca_bundle": "lab-ca.pem" } } }
+            }
+        }))
+        .unwrap();
+        let host = inventory.hosts.get("r1").unwrap();
+
+        let (params, provenance) = inventory.explain_connection_params(host, "gnmi");
+        assert_eq!(
+            params.tls,
+            Some(
+                TlsConfig::new()
+                    .ca_bundle("lab-ca.pem")
+                    .client_identity("r1.crt", "r1.key")
+                    .verify(false)
+            )
+        );
+        assert_eq!(provenance.tls, Some(ParamSource::HostConnectionOptions));
+        assert!(!params.tls.as_ref().unwrap().verifies());
+        assert_eq!(params.tls.unwrap().server_name_for("r1"), "r1");
+
+        assert_eq!(inventory.resolve_connection_params(host, "ssh").tls, None);
+    }
+}
@@ -0,0 +1,256 @@
+use super::{validate_common, Inventory, InventoryError, ParentGroups};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// How serious a [`ValidationIssue`] or an annotation is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// Informational only, e.g. an [`Annotation`](super::Annotation) noting
+    /// a certificate expiry date. Never reported by validation.
+    Info,
+    /// Suspicious but usable, e.g. two hosts sharing an address.
+    Warning,
+    /// The entry is invalid, e.g. port `0`.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Info => f.write_str("info"),
+            Severity::Warning => f.write_str("warning"),
+            Severity::Error => f.write_str("error"),
+        }
+    }
+}
+
+/// A problem found by [`Inventory::validate_with`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    pub severity: Severity,
+    /// Dotted path of the entry, e.g. `hosts.r1`.
+    pub location: String,
+    pub message: String,
+}
+
+/// Displays `severity: location: message`.
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}: {}", self.severity, self.location, self.message)
+    }
+}
+
+/// Which issues make [`Inventory::validate_with`] fail.
+///
+/// Parsed from `strict`, `standard` or `lenient`, so it can be read from a
+/// config file or a command line flag.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationProfile {
+    /// Fails on warnings and errors.
+    Strict,
+    /// Fails on errors, logs warnings.
+    #[default]
+    Standard,
+    /// Never fails, logs every issue.
+    Lenient,
+}
+
+impl ValidationProfile {
+    fn fails_on(self, severity: Severity) -> bool {
+        match self {
+            ValidationProfile::Strict => severity >= Severity::Warning,
+            ValidationProfile::Standard => severity == Severity::Error,
+            ValidationProfile::Lenient => false,
+        }
+    }
+}
+
+impl FromStr for ValidationProfile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "strict" => Ok(ValidationProfile::Strict),
+            "standard" => Ok(ValidationProfile::Standard),
+            "lenient" => Ok(ValidationProfile::Lenient),
+            other => Err(format!(
+                "unknown validation profile `{other}`, expected strict, standard or lenient"
+            )),
+        }
+    }
+}
+
+impl Inventory {
+    /// Checks the whole inventory and returns every issue found, without
+    /// storing them.
+    ///
+    /// Errors are invalid host and group fields. Warnings are references to
+    /// groups that are not defined, hosts stored under a key different from
+    /// their name, hosts sharing an address and contexts of missing parents.
+    pub fn check(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        let mut issue = |severity, location: String, message: String| {
+            issues.push(ValidationIssue {
+                severity,
+                location,
+                message,
+            })
+        };
+        let is_defined = |group: &str| {
+            self.groups
+                .as_ref()
+                .is_some_and(|groups| groups.get(group).is_some())
+        };
+        let undefined_groups = |location: &str, parents: Option<&ParentGroups>| {
+            parents
+                .into_iter()
+                .flat_map(|parents| parents.iter())
+                .filter(|group| !group.trim().is_empty() && !is_defined(group))
+                .map(|group| {
+                    (
+                        location.to_string(),
+                        format!("group `{group}` is not defined"),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut warnings = Vec::new();
+        for (key, host) in self.hosts.iter_str() {
+            let location = format!("hosts.{key}");
+            if let Err(InventoryError::Validation { message, .. }) = validate_common(
+                &host.name,
+                host.hostname.as_deref(),
+                host.port,
+                host.groups.as_ref(),
+            ) {
+                issue(Severity::Error, location.clone(), message);
+            }
+            if host.name != key {
+                warnings.push((location.clone(), format!("host is named `{}`", host.name)));
+            }
+            warnings.extend(undefined_groups(&location, host.groups.as_ref()));
+            if let Some(context) = host.device_context() {
+                if self.hosts.get(&context.parent).is_none() {
+                    warnings.push((
+                        location,
+                        format!("context parent `{}` is not defined", context.parent),
+                    ));
+                }
+            }
+        }
+        for (name, group) in self.groups.iter().flat_map(|groups| groups.iter_str()) {
+            let location = format!("groups.{name}");
+            if let Err(InventoryError::Validation { message, .. }) = validate_common(
+                name,
+                group.hostname.as_deref(),
+                group.port,
+                group.groups.as_ref(),
+            ) {
+                issue(Severity::Error, location.clone(), message);
+            }
+            warnings.extend(undefined_groups(&location, group.groups.as_ref()));
+        }
+        for (address, hosts) in self.hosts.duplicate_hostnames() {
+            warnings.push((
+                format!("hosts.{}", hosts[0]),
+                format!(
+                    "address `{address}` is shared with {}",
+                    hosts[1..].join(", ")
+                ),
+            ));
+        }
+        for (location, message) in warnings {
+            issue(Severity::Warning, location, message);
+        }
+        issues
+    }
+
+    /// Runs [`Inventory::check`], stores the issues in
+    /// [`Inventory::issues`] and logs them.
+    ///
+    /// Returns an error for the first issue `profile` fails on. The issues
+    /// are stored whether or not validation fails.
+    pub fn validate_with(&mut self, profile: ValidationProfile) -> Result<(), InventoryError> {
+        self.issues = self.check();
+        for issue in &self.issues {
+            match issue.severity {
+                Severity::Error => log::error!("{issue}"),
+                Severity::Warning => log::warn!("{issue}"),
+                Severity::Info => log::info!("{issue}"),
+            }
+        }
+        match self
+            .issues
+            .iter()
+            .find(|issue| profile.fails_on(issue.severity))
+        {
+            Some(issue) => Err(InventoryError::Validation {
+                name: issue.location.clone(),
+                message: issue.message.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn inventory() -> Inventory {
+        serde_json::from_value(json!({
+            "hosts": {
+                "r1": { "name": "r1", "hostname": "10.0.0.1", "groups": ["core", "edge"] },
+                "r2": { "name": "r2", "hostname": "10.0.0.2", "port": 0 },
+                "r3": { "name": "r3", "hostname": "10.0.0.1" }
+            },
+            "groups": { "core": {} }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_check() {
+        let issues: Vec<String> = inventory()
+            .check()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            issues,
+            [
+                "error: hosts.r2: port must be between 1 and 65535",
+                "warning: hosts.r1: group `edge` is not defined",
+                "warning: hosts.r1: address `10.0.0.1` is shared with r3",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validation_profiles() {
+        let mut inventory = inventory();
+        assert!(inventory.validate_with(ValidationProfile::Lenient).is_ok());
+        assert_eq!(inventory.issues.len(), 3);
+
+        let error = inventory
+            .validate_with(ValidationProfile::Standard)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid inventory entry `hosts.r2`: port must be between 1 and 65535"
+        );
+
+        inventory.hosts["r2"].port = Some(22);
+        assert!(inventory.validate_with(ValidationProfile::Standard).is_ok());
+        assert!(inventory.validate_with(ValidationProfile::Strict).is_err());
+        assert_eq!(inventory.issues.len(), 2);
+
+        assert_eq!("strict".parse(), Ok(ValidationProfile::Strict));
+        assert!("loose".parse::<ValidationProfile>().is_err());
+    }
+}
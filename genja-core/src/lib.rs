@@ -1,19 +1,89 @@
+//! Core inventory model and runtime types for genja.
+//!
+//! # Features
+//!
+//! * `python` (default) - Python bindings through pyo3.
+//! * `schema` (default) - JSON schema generation for the inventory types.
+//! * `query` (default) - JMESPath queries through `Inventory::query` and
+//!   `Genja::filter_query`.
+//! * `redact` (default) - Regex and key based redaction of output through
+//!   the `redact` module.
+//! * `jsonrpc` - JSON-RPC over HTTPS connection plugin, see
+//!   `connections::JsonRpcConnection`. Requests are sent with the system
+//!   `curl`.
+//!
+//! With `--no-default-features` the inventory model, `NatString` and
+//! `CustomTreeMap` only depend on serde and a few small crates, and the crate
+//! builds for `wasm32-unknown-unknown`:
+//!
+//! ```text
+//! cargo build -p genja-core --no-default-features --target wasm32-unknown-unknown
+//! ```
+
+pub mod assert;
+pub mod connections;
+pub mod facts;
 pub mod inventory;
+#[cfg(feature = "redact")]
+pub mod redact;
+#[cfg(test)]
+mod test_util;
 pub mod types;
 
 // Re-export commonly used types
-use inventory::{Host, Inventory};
-use std::sync::Arc;
+use connections::DeviceErrorKind;
+use inventory::InventoryError;
+use inventory::{Connection, FrozenInventory, GroupLimiter, Host, Inventory, SharedInventory};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::SystemTime;
 pub use types::{CustomTreeMap, NatString};
 
+/// Outcome of [`Genja::preconnect`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PreconnectReport {
+    /// Hosts with an open connection, including ones that were already open.
+    pub connected: Vec<String>,
+    /// Hosts whose connection failed to open, with the plugin's error.
+    pub failed: BTreeMap<String, String>,
+    /// Hosts not connected because the scheduling window of one of their
+    /// groups is closed, with the reason. They can be retried later.
+    pub skipped: BTreeMap<String, String>,
+}
+
+impl PreconnectReport {
+    /// The failed hosts grouped by the [`DeviceErrorKind`] of their error.
+    pub fn failures_by_kind(&self) -> BTreeMap<DeviceErrorKind, Vec<&str>> {
+        let mut kinds: BTreeMap<DeviceErrorKind, Vec<&str>> = BTreeMap::new();
+        for (host, error) in &self.failed {
+            kinds
+                .entry(DeviceErrorKind::classify(error))
+                .or_default()
+                .push(host);
+        }
+        kinds
+    }
+}
+
 /// Represents a Nornir inventory and runtime environment.
 ///
 /// `host_ids` is equal to a Vec of NatString's due to the wrapper used
 /// to store the CustomTreeMap's keys.
+///
+/// A view keeps the inventory it was created from. When the
+/// [`SharedInventory`] behind it is replaced the view is stale until
+/// [`Genja::refresh`] is called. Views created with [`Genja::filter`] always
+/// start from the current inventory.
 #[derive(Debug)]
 pub struct Genja {
-    inventory: Arc<Inventory>,
+    source: Arc<SharedInventory>,
+    generation: u64,
+    inventory: Arc<FrozenInventory>,
     host_ids: Arc<Vec<NatString>>,
+    /// Set for unfiltered views, which select every host after a refresh.
+    selects_all: bool,
     // config: Arc<Config>,
     // data: Arc<GlobalState>,
     // processors: Arc<Processors>,
@@ -21,13 +91,37 @@ pub struct Genja {
 }
 
 impl Genja {
-    /// The host_ids are a Vec of owned NatString's, therefore they need
-    /// to be cloned from the inventory's CustomTreeMap's keys.
+    /// Freezes `inventory`, see [`Inventory::freeze`], and selects all of
+    /// its hosts.
     pub fn new(inventory: Inventory) -> Self {
+        Self::from_frozen(inventory.freeze())
+    }
+
+    /// Loads the inventory with the plugin registered as `name`, see
+    /// [`inventory::register_inventory_plugin`].
+    pub fn with_inventory_plugin(
+        name: &str,
+        options: &serde_json::Value,
+    ) -> Result<Self, InventoryError> {
+        inventory::load_inventory_plugin(name, options).map(Self::new)
+    }
+
+    /// Wraps an already frozen inventory in a new [`SharedInventory`] and
+    /// selects all of its hosts, see [`Genja::from_shared`].
+    pub fn from_frozen(inventory: Arc<FrozenInventory>) -> Self {
+        Self::from_shared(Arc::new(SharedInventory::new(inventory)))
+    }
+
+    /// Selects all hosts of the current inventory of `source`.
+    pub fn from_shared(source: Arc<SharedInventory>) -> Self {
+        let (generation, inventory) = source.current();
         let host_ids = inventory.hosts.keys().cloned().collect();
         Self {
-            inventory: Arc::new(inventory),
+            source,
+            generation,
+            inventory,
             host_ids: Arc::new(host_ids),
+            selects_all: true,
             // config: Arc::new(Config::default()),
             // data: Arc::new(GlobalState::default()),
             // processors: Arc::new(Processors::default()),
@@ -37,16 +131,19 @@ impl Genja {
     /// The `host_key` is a NatString due to the wrapper used to store the CustomTreeMap's keys.
     /// The method `into` converts it to a string.
     pub fn filter(&self, pred: impl Fn(&Host) -> bool) -> Self {
-        let host_ids = self
-            .inventory
+        let (generation, inventory) = self.source.current();
+        let host_ids = inventory
             .hosts
             .iter()
             .filter_map(|(id, host)| if pred(host) { Some(id.clone()) } else { None })
             .collect();
 
         Self {
-            inventory: Arc::clone(&self.inventory),
+            source: Arc::clone(&self.source),
+            generation,
+            inventory,
             host_ids: Arc::new(host_ids),
+            selects_all: false,
             // config: Arc::clone(&self.config),
             // data: Arc::clone(&self.data),
             // processors: Arc::clone(&self.processors),
@@ -54,6 +151,77 @@ impl Genja {
         }
     }
 
+    /// Filters hosts with a JMESPath expression evaluated against each host.
+    ///
+    /// Hosts for which the expression yields a truthy value are kept, e.g.
+    /// `data.role == 'core_router'` or `contains(groups, 'edge')`.
+    #[cfg(feature = "query")]
+    pub fn filter_query(&self, expression: &str) -> Result<Self, InventoryError> {
+        let expr = inventory::compile_query(expression)?;
+        let (generation, current) = self.source.current();
+        let mut host_ids = Vec::new();
+        for (id, host) in current.hosts.iter() {
+            if inventory::query_matches(&expr, expression, host)? {
+                host_ids.push(id.clone());
+            }
+        }
+
+        Ok(Self {
+            source: Arc::clone(&self.source),
+            generation,
+            inventory: current,
+            host_ids: Arc::new(host_ids),
+            selects_all: false,
+        })
+    }
+
+    /// The generation of the inventory this view was created from.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns `true` if the shared inventory was replaced since this view
+    /// was created or refreshed.
+    pub fn is_stale(&self) -> bool {
+        self.source.generation() != self.generation
+    }
+
+    /// Moves a stale view to the current inventory and returns `true`, or
+    /// returns `false` if it was up to date.
+    ///
+    /// Unfiltered views select every current host. Filtered views keep the
+    /// selected hosts that still exist; hosts added since are not selected,
+    /// filter again to pick them up.
+    pub fn refresh(&mut self) -> bool {
+        let (generation, inventory) = self.source.current();
+        if generation == self.generation {
+            return false;
+        }
+        let host_ids = if self.selects_all {
+            inventory.hosts.keys().cloned().collect()
+        } else {
+            self.host_ids
+                .iter()
+                .filter(|id| inventory.hosts.get(id).is_some())
+                .cloned()
+                .collect()
+        };
+        log::debug!(
+            "refreshed view from inventory generation {} to {generation}",
+            self.generation
+        );
+        self.generation = generation;
+        self.inventory = inventory;
+        self.host_ids = Arc::new(host_ids);
+        true
+    }
+
+    /// The frozen inventory. Use [`FrozenInventory::thaw`] on a clone to
+    /// edit a copy.
+    pub fn inventory(&self) -> &Arc<FrozenInventory> {
+        &self.inventory
+    }
+
     pub fn iter_hosts(&self) -> impl Iterator<Item = &Host> {
         self.host_ids
             .iter()
@@ -67,4 +235,159 @@ impl Genja {
     pub fn host_count(&self) -> usize {
         self.host_ids.len()
     }
+
+    /// Opens a `plugin` connection to every selected host before the first
+    /// task runs, using up to `concurrency` threads.
+    ///
+    /// Connections are created with `ctor` through the inventory's
+    /// `ConnectionManager` and opened with
+    /// [`Inventory::resolve_connection_params`]. Connections that are
+    /// already alive are left as they are. Failed connections stay in the
+    /// manager so a later task can retry opening them.
+    ///
+    /// The [`SchedulingHints`](inventory::SchedulingHints) of the hosts'
+    /// groups are enforced through a [`GroupLimiter`] while connections
+    /// open: the group permit is released once the connection is open, so
+    /// `max_concurrent` limits connection attempts here, not the work done
+    /// afterwards. Tasks limiting their changes per group hold a permit of
+    /// [`Genja::group_limiter`] for the whole task. Hosts outside the time
+    /// window of one of their groups are reported as skipped.
+    pub fn preconnect<C, F>(&self, plugin: &str, concurrency: usize, ctor: F) -> PreconnectReport
+    where
+        C: Connection + 'static,
+        F: Fn() -> C + Sync,
+    {
+        let hosts: Vec<&Host> = self.iter_hosts().collect();
+        let next = AtomicUsize::new(0);
+        let report = Mutex::new(PreconnectReport::default());
+        let limiter = GroupLimiter::new(&self.inventory);
+
+        thread::scope(|scope| {
+            for _ in 0..concurrency.clamp(1, hosts.len().max(1)) {
+                scope.spawn(|| {
+                    while let Some(host) = hosts.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        if let Some(group) = limiter.closed_window(host, SystemTime::now()) {
+                            let reason =
+                                format!("outside the scheduling window of group `{group}`");
+                            log::info!("{}: {plugin} connection skipped: {reason}", host.name);
+                            let mut report = report.lock().unwrap_or_else(|e| e.into_inner());
+                            report.skipped.insert(host.name.clone(), reason);
+                            continue;
+                        }
+                        let result = {
+                            let _permit = limiter.acquire(host);
+                            self.open_connection(host, plugin, &ctor)
+                        };
+                        let mut report = report.lock().unwrap_or_else(|e| e.into_inner());
+                        match result {
+                            Ok(()) => report.connected.push(host.name.clone()),
+                            Err(error) => {
+                                log::warn!("{}: {plugin} connection failed: {error}", host.name);
+                                report.failed.insert(host.name.clone(), error);
+                            }
+                        }
+                    }
+                });
+            }
+        });
+
+        let mut report = report.into_inner().unwrap_or_else(|e| e.into_inner());
+        report.connected.sort_by(|a, b| natord::compare(a, b));
+        report
+    }
+
+    /// A [`GroupLimiter`] enforcing the scheduling hints of this view's
+    /// inventory. A task holds the [`GroupPermit`](inventory::GroupPermit)
+    /// of a host for as long as it works on it, e.g. to allow only two
+    /// simultaneous changes per POP.
+    pub fn group_limiter(&self) -> GroupLimiter<'_> {
+        GroupLimiter::new(&self.inventory)
+    }
+
+    /// Returns a view of the same selection without access to connections.
+    pub fn read_only(&self) -> ReadOnlyGenja {
+        ReadOnlyGenja(Self {
+            source: Arc::clone(&self.source),
+            generation: self.generation,
+            inventory: Arc::clone(&self.inventory),
+            host_ids: Arc::clone(&self.host_ids),
+            selects_all: self.selects_all,
+        })
+    }
+
+    fn open_connection<C, F>(&self, host: &Host, plugin: &str, ctor: &F) -> Result<(), String>
+    where
+        C: Connection + 'static,
+        F: Fn() -> C,
+    {
+        let key = self.inventory.connection_key(host, plugin);
+        let connection = self.inventory.connections.get_or_create(key, ctor);
+        let mut connection = connection
+            .lock()
+            .map_err(|_| "connection lock poisoned".to_string())?;
+        if connection.is_alive() {
+            return Ok(());
+        }
+        let params = self.inventory.resolve_connection_params(host, plugin);
+        connection.open(&params)
+    }
+}
+
+/// A read-only view of a [`Genja`], see [`Genja::read_only`].
+///
+/// It can select and read hosts but has no access to the inventory's
+/// `ConnectionManager`, so it cannot open, close or use connections. Hand it
+/// to reporting or monitoring code that must not touch devices.
+#[derive(Debug)]
+pub struct ReadOnlyGenja(Genja);
+
+impl ReadOnlyGenja {
+    pub fn filter(&self, pred: impl Fn(&Host) -> bool) -> Self {
+        ReadOnlyGenja(self.0.filter(pred))
+    }
+
+    /// See [`Genja::filter_query`].
+    #[cfg(feature = "query")]
+    pub fn filter_query(&self, expression: &str) -> Result<Self, InventoryError> {
+        self.0.filter_query(expression).map(ReadOnlyGenja)
+    }
+
+    /// Runs a JMESPath query over the whole inventory, see `Inventory::query`.
+    #[cfg(feature = "query")]
+    pub fn query(&self, expression: &str) -> Result<serde_json::Value, InventoryError> {
+        self.0.inventory.query(expression)
+    }
+
+    /// See [`Genja::is_stale`].
+    pub fn is_stale(&self) -> bool {
+        self.0.is_stale()
+    }
+
+    /// See [`Genja::refresh`].
+    pub fn refresh(&mut self) -> bool {
+        self.0.refresh()
+    }
+
+    /// Returns the host `name` if it is selected, like
+    /// [`ReadOnlyGenja::iter_hosts`]. Use [`ReadOnlyGenja::iter_all_hosts`]
+    /// to reach hosts outside the selection.
+    pub fn get_host(&self, name: &str) -> Option<&Host> {
+        let genja = &self.0;
+        if !genja.selects_all && !genja.host_ids.iter().any(|id| id.as_str() == name) {
+            return None;
+        }
+        genja.inventory.hosts.get(name)
+    }
+
+    pub fn iter_hosts(&self) -> impl Iterator<Item = &Host> {
+        self.0.iter_hosts()
+    }
+
+    pub fn iter_all_hosts(&self) -> impl Iterator<Item = (&NatString, &Host)> {
+        self.0.iter_all_hosts()
+    }
+
+    pub fn host_count(&self) -> usize {
+        self.0.host_count()
+    }
 }
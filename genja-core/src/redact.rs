@@ -0,0 +1,286 @@
+//! Redaction of secrets in output before it is reported or stored.
+//!
+//! A [`Redactor`] masks values under secret-looking keys in JSON output and
+//! text matching regular expressions, e.g. SNMP communities or BGP passwords
+//! in `show running-config` output. Rules can be read from configuration as
+//! [`RedactionRules`].
+//!
+//! # Examples
+//!
+//! ```
+//! use genja_core::redact::Redactor;
+//!
+//! let redactor = Redactor::network_defaults();
+//! let config = "snmp-server community public RO\nneighbor 192.0.2.1 password 7 0822455D0A16";
+//!
+//! assert_eq!(
+//!     redactor.redact_str(config),
+//!     "snmp-server community ******** RO\nneighbor 192.0.2.1 password 7 ********"
+//! );
+//! ```
+
+use crate::connections::CommandOutput;
+use crate::inventory::is_secret_key;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+
+/// Default replacement for redacted values.
+pub const MASK: &str = "********";
+
+/// Patterns for secrets commonly found in network device configurations.
+/// The first capture group is masked.
+const NETWORK_PATTERNS: &[&str] = &[
+    r"(?m)snmp-server community (\S+)",
+    r"(?m)snmp-server user \S+ \S+ (?:v3 )?auth \S+ (\S+)",
+    r"(?m)\bpriv (?:des|3des|aes(?: \d+)?) (\S+)",
+    r"(?m)\bneighbor \S+ password (?:\d )?(\S+)",
+    r"(?m)\b(?:authentication-key|secret|key-string) (?:\d )?(\S+)",
+    r#"(?m)\b(?:authentication-key|secret) "([^"]+)""#,
+];
+
+/// Redaction rules as found in configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RedactionRules {
+    /// Case-insensitive key fragments; values under matching keys are
+    /// masked entirely.
+    #[serde(default)]
+    pub keys: Vec<String>,
+    /// Regular expressions; the first capture group is masked, or the whole
+    /// match without one.
+    #[serde(default)]
+    pub patterns: Vec<String>,
+    /// Replacement text. Defaults to [`MASK`].
+    pub mask: Option<String>,
+    /// Adds the rules of [`Redactor::network_defaults`].
+    #[serde(default)]
+    pub network_defaults: bool,
+}
+
+/// Masks secrets in text, JSON values and command output.
+#[derive(Debug, Clone)]
+pub struct Redactor {
+    keys: Vec<String>,
+    /// Masks keys matching [`is_secret_key`].
+    secret_keys: bool,
+    patterns: Vec<Regex>,
+    mask: String,
+}
+
+impl Default for Redactor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Redactor {
+    /// A redactor without rules.
+    pub fn new() -> Self {
+        Redactor {
+            keys: Vec::new(),
+            secret_keys: false,
+            patterns: Vec::new(),
+            mask: MASK.to_string(),
+        }
+    }
+
+    /// A redactor for community strings, SNMPv3 keys, BGP and routing
+    /// protocol passwords and values under keys matching
+    /// [`is_secret_key`], the heuristic [`Inventory::anonymize`] uses.
+    ///
+    /// [`Inventory::anonymize`]: crate::inventory::Inventory::anonymize
+    pub fn network_defaults() -> Self {
+        let redactor = Redactor {
+            secret_keys: true,
+            ..Self::new()
+        };
+        NETWORK_PATTERNS.iter().fold(redactor, |redactor, pattern| {
+            redactor
+                .pattern(pattern)
+                .expect("network redaction patterns are valid")
+        })
+    }
+
+    /// Builds a redactor from configuration.
+    pub fn from_rules(rules: &RedactionRules) -> Result<Self, regex::Error> {
+        let mut redactor = if rules.network_defaults {
+            Self::network_defaults()
+        } else {
+            Self::new()
+        };
+        if let Some(mask) = &rules.mask {
+            redactor = redactor.mask(mask);
+        }
+        for key in &rules.keys {
+            redactor = redactor.key(key);
+        }
+        for pattern in &rules.patterns {
+            redactor = redactor.pattern(pattern)?;
+        }
+        Ok(redactor)
+    }
+
+    /// Masks values under keys containing `fragment`, ignoring case.
+    pub fn key(mut self, fragment: &str) -> Self {
+        self.keys.push(fragment.to_lowercase());
+        self
+    }
+
+    /// Masks text matching `pattern`: the first capture group, or the whole
+    /// match if the pattern has none.
+    pub fn pattern(mut self, pattern: &str) -> Result<Self, regex::Error> {
+        self.patterns.push(Regex::new(pattern)?);
+        Ok(self)
+    }
+
+    pub fn mask(mut self, mask: &str) -> Self {
+        self.mask = mask.to_string();
+        self
+    }
+
+    fn is_secret_key(&self, key: &str) -> bool {
+        if self.secret_keys && is_secret_key(key) {
+            return true;
+        }
+        let key = key.to_lowercase();
+        self.keys.iter().any(|fragment| key.contains(fragment))
+    }
+
+    /// Returns `text` with every pattern match masked.
+    pub fn redact_str<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        let mut text = Cow::Borrowed(text);
+        for pattern in &self.patterns {
+            if !pattern.is_match(&text) {
+                continue;
+            }
+            let redacted = pattern.replace_all(&text, |captures: &regex::Captures<'_>| {
+                let whole = captures.get(0).expect("group 0 always matches");
+                match captures.get(1) {
+                    Some(secret) => {
+                        let (start, end) =
+                            (secret.start() - whole.start(), secret.end() - whole.start());
+                        let whole = whole.as_str();
+                        format!("{}{}{}", &whole[..start], self.mask, &whole[end..])
+                    }
+                    None => self.mask.clone(),
+                }
+            });
+            text = Cow::Owned(redacted.into_owned());
+        }
+        text
+    }
+
+    /// Masks values under secret keys and pattern matches in strings, at
+    /// any depth.
+    pub fn redact_value(&self, value: &mut Value) {
+        match value {
+            Value::String(text) => {
+                if let Cow::Owned(redacted) = self.redact_str(text) {
+                    *text = redacted;
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.redact_value(item)),
+            Value::Object(map) => {
+                for (key, item) in map.iter_mut() {
+                    if self.is_secret_key(key) && !item.is_null() {
+                        *item = Value::String(self.mask.clone());
+                    } else {
+                        self.redact_value(item);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Masks pattern matches in the stdout and stderr of a command.
+    pub fn redact_output(&self, output: &mut CommandOutput) {
+        for text in [&mut output.stdout, &mut output.stderr] {
+            if let Cow::Owned(redacted) = self.redact_str(text) {
+                *text = redacted;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_network_defaults() {
+        let redactor = Redactor::network_defaults();
+        let config = "\
+snmp-server user monitor ops v3 auth sha AuthPass123 priv aes 128 PrivPass456
+router bgp 65000
+ neighbor 192.0.2.1 password S3cret!
+interface Gi0/1
+ ip ospf authentication-key 7 0822455D0A16
+ description uplink";
+        assert_eq!(
+            redactor.redact_str(config),
+            "\
+snmp-server user monitor ops v3 auth sha ******** priv aes 128 ********
+router bgp 65000
+ neighbor 192.0.2.1 password ********
+interface Gi0/1
+ ip ospf authentication-key 7 ********
+ description uplink"
+        );
+        assert!(matches!(
+            redactor.redact_str("show version"),
+            Cow::Borrowed(_)
+        ));
+
+        let mut value = json!({ "SNMPCommunity": "public", "passwd": "x", "keyboard": "us" });
+        redactor.redact_value(&mut value);
+        assert_eq!(
+            value,
+            json!({ "SNMPCommunity": MASK, "passwd": MASK, "keyboard": "us" })
+        );
+    }
+
+    #[test]
+    fn test_rules_from_config() {
+        let rules: RedactionRules = serde_json::from_value(json!({
+            "keys": ["psk"],
+            "patterns": ["token=\\w+"],
+            "mask": "<redacted>"
+        }))
+        .unwrap();
+        let redactor = Redactor::from_rules(&rules).unwrap();
+
+        let mut value = json!({
+            "tunnel": { "ike_psk": "abc", "peer": "192.0.2.9" },
+            "log": ["GET /api?token=deadbeef ok"],
+            "password": "kept without network defaults"
+        });
+        redactor.redact_value(&mut value);
+        assert_eq!(
+            value,
+            json!({
+                "tunnel": { "ike_psk": "<redacted>", "peer": "192.0.2.9" },
+                "log": ["GET /api?<redacted> ok"],
+                "password": "kept without network defaults"
+            })
+        );
+
+        let mut output = CommandOutput {
+            stdout: "token=abc".to_string(),
+            stderr: String::new(),
+            exit_code: Some(0),
+            timed_out: false,
+        };
+        redactor.redact_output(&mut output);
+        assert_eq!(output.stdout, "<redacted>");
+
+        let invalid = RedactionRules {
+            patterns: vec!["(".to_string()],
+            ..RedactionRules::default()
+        };
+        assert!(Redactor::from_rules(&invalid).is_err());
+    }
+}
@@ -0,0 +1,42 @@
+//! Fixtures shared by the unit tests.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A fresh directory under the system temp directory, removed when dropped,
+/// including when the test panics.
+pub(crate) struct TempDir(PathBuf);
+
+impl TempDir {
+    /// `name` keeps the directories of concurrently running tests apart.
+    pub(crate) fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("genja-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        TempDir(dir)
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.0
+    }
+
+    pub(crate) fn join(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.0.join(path)
+    }
+
+    /// Writes `content` to `name`, creating its parent directories.
+    pub(crate) fn write(&self, name: &str, content: &str) -> PathBuf {
+        let path = self.0.join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(&path, content).unwrap();
+        path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
@@ -1,13 +1,16 @@
 use natord::compare;
+#[cfg(feature = "python")]
 use pyo3::prelude::*;
+#[cfg(feature = "schema")]
 use schemars::{JsonSchema, Schema, SchemaGenerator};
 // use serde::ser::SerializeMap;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::cmp::Ordering;
+use std::collections::btree_map::{Iter, ValuesMut};
 use std::collections::BTreeMap;
 use std::fmt;
-use std::ops::{Deref, DerefMut};
+use std::ops::{Deref, DerefMut, Index, IndexMut};
 // pub mod inventory
 
 pub trait DerefTarget {
@@ -33,7 +36,8 @@ pub trait DerefTarget {
 /// assert!(s1 < s2);
 /// // s1 < s2 in natural order (2 < 10)
 /// ```
-#[derive(PartialEq, Eq, Clone, JsonSchema, Serialize, Deserialize)]
+#[derive(PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(JsonSchema))]
 pub struct NatString(String);
 
 impl Deref for NatString {
@@ -72,6 +76,52 @@ impl NatString {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// The number the string ends with, e.g. `12` for `leaf-r12`.
+    ///
+    /// Returns `None` when the string doesn't end with a digit or the number
+    /// doesn't fit in a `u64`.
+    ///
+    /// ```
+    /// # use genja_core::NatString;
+    /// assert_eq!(NatString::new("leaf-r12".to_string()).numeric_suffix(), Some(12));
+    /// assert_eq!(NatString::new("leaf-r12a".to_string()).numeric_suffix(), None);
+    /// ```
+    pub fn numeric_suffix(&self) -> Option<u64> {
+        numeric_suffix(&self.0)
+    }
+
+    /// The string without its numeric suffix, e.g. `leaf-r` for `leaf-r12`.
+    pub fn stem(&self) -> &str {
+        let digits = self.0.bytes().rev().take_while(u8::is_ascii_digit).count();
+        &self.0[..self.0.len() - digits]
+    }
+
+    /// Splits the string into alternating runs of digits and non-digits,
+    /// e.g. `rack10-slot2` into `["rack", "10", "-slot", "2"]`.
+    pub fn split_alpha_numeric(&self) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let mut start = 0;
+        let bytes = self.0.as_bytes();
+        for index in 1..bytes.len() {
+            if bytes[index].is_ascii_digit() != bytes[index - 1].is_ascii_digit() {
+                parts.push(&self.0[start..index]);
+                start = index;
+            }
+        }
+        if start < bytes.len() {
+            parts.push(&self.0[start..]);
+        }
+        parts
+    }
+}
+
+pub(crate) fn numeric_suffix(s: &str) -> Option<u64> {
+    let digits = s.bytes().rev().take_while(u8::is_ascii_digit).count();
+    if digits == 0 {
+        return None;
+    }
+    s[s.len() - digits..].parse().ok()
 }
 
 impl fmt::Debug for NatString {
@@ -189,6 +239,46 @@ impl<V> CustomTreeMap<V> {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// The keys as `&str`, in natural order.
+    pub fn keys_str(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator + '_ {
+        self.0.keys().map(|key| key.as_str())
+    }
+
+    /// The entries with `&str` keys, in natural order.
+    pub fn iter_str(&self) -> impl DoubleEndedIterator<Item = (&str, &V)> + ExactSizeIterator + '_ {
+        self.0.iter().map(|(key, value)| (key.as_str(), value))
+    }
+
+    /// Mutable references to the values, in natural order of their keys.
+    pub fn values_mut(&mut self) -> ValuesMut<'_, NatString, V> {
+        self.0.values_mut()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a CustomTreeMap<V> {
+    type Item = (&'a NatString, &'a V);
+    type IntoIter = Iter<'a, NatString, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<V> Index<&str> for CustomTreeMap<V> {
+    type Output = V;
+
+    fn index(&self, key: &str) -> &V {
+        self.get(key)
+            .unwrap_or_else(|| panic!("CustomTreeMap has no entry `{key}`"))
+    }
+}
+
+impl<V> IndexMut<&str> for CustomTreeMap<V> {
+    fn index_mut(&mut self, key: &str) -> &mut V {
+        self.get_mut(key)
+            .unwrap_or_else(|| panic!("CustomTreeMap has no entry `{key}`"))
+    }
 }
 
 impl<V> Default for CustomTreeMap<V> {
@@ -197,6 +287,7 @@ impl<V> Default for CustomTreeMap<V> {
     }
 }
 
+#[cfg(feature = "schema")]
 impl<V> JsonSchema for CustomTreeMap<V>
 where
     V: JsonSchema,
@@ -210,13 +301,59 @@ where
     }
 }
 
+/// A string deserialized without allocating when the input allows it.
+///
+/// Deserializers that can hand out borrowed strings (e.g. `serde_json` reading
+/// from a `&str` without escapes) produce `Cow::Borrowed`; everything else
+/// falls back to an owned `String`. Used where a string is only inspected
+/// before deciding whether to keep it: duplicate `ParentGroups` entries and
+/// host keys rejected by the `IncrementalLoader` filter.
+///
+/// Strings that are kept, such as `Host` and `Group` fields, platforms and
+/// `Data` keys, are owned by public types and still allocate once each.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CowStr<'de>(pub(crate) Cow<'de, str>);
+
+impl<'de> Deserialize<'de> for CowStr<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct CowStrVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for CowStrVisitor {
+            type Value = CowStr<'de>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E> {
+                Ok(CowStr(Cow::Borrowed(value)))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(CowStr(Cow::Owned(value.to_string())))
+            }
+
+            fn visit_string<E>(self, value: String) -> Result<Self::Value, E> {
+                Ok(CowStr(Cow::Owned(value)))
+            }
+        }
+
+        deserializer.deserialize_str(CowStrVisitor)
+    }
+}
+
 /// Formats the sum of two numbers as string.
+#[cfg(feature = "python")]
 #[pyfunction]
 fn sum_as_string(a: usize, b: usize) -> PyResult<String> {
     Ok((a + b).to_string())
 }
 
 /// A Python module implemented in Rust.
+#[cfg(feature = "python")]
 #[pymodule]
 fn genja_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(sum_as_string, m)?)?;
@@ -234,6 +371,69 @@ mod tests {
         assert!(s1 < s2);
     }
 
+    #[test]
+    fn test_nat_string_numeric_helpers() {
+        let name = NatString::new("rack10-slot2".to_string());
+        assert_eq!(name.numeric_suffix(), Some(2));
+        assert_eq!(name.stem(), "rack10-slot");
+        assert_eq!(name.split_alpha_numeric(), ["rack", "10", "-slot", "2"]);
+
+        let plain = NatString::new("spine".to_string());
+        assert_eq!(plain.numeric_suffix(), None);
+        assert_eq!(plain.stem(), "spine");
+        assert_eq!(plain.split_alpha_numeric(), ["spine"]);
+        assert!(NatString::new(String::new())
+            .split_alpha_numeric()
+            .is_empty());
+        assert_eq!(
+            NatString::new("r99999999999999999999".to_string()).numeric_suffix(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cow_str_borrows_when_possible() {
+        let borrowed: CowStr = serde_json::from_str(r#""core""#).unwrap();
+        assert!(matches!(borrowed.0, Cow::Borrowed("core")));
+
+        let escaped: CowStr = serde_json::from_str(r#""co\u0072e""#).unwrap();
+        assert!(matches!(escaped.0, Cow::Owned(ref s) if s == "core"));
+    }
+
+    #[test]
+    fn test_custom_tree_map_str_iterators() {
+        let mut tree = CustomTreeMap::new();
+        tree.insert("r10", 10);
+        tree.insert("r2", 2);
+        assert_eq!(tree.keys_str().collect::<Vec<_>>(), ["r2", "r10"]);
+        assert_eq!(tree.iter_str().next_back(), Some(("r10", &10)));
+        for value in tree.values_mut() {
+            *value *= 2;
+        }
+        assert_eq!(
+            tree.iter_str().collect::<Vec<_>>(),
+            [("r2", &4), ("r10", &20)]
+        );
+    }
+
+    #[test]
+    fn test_delegate_to_custom_tree_map() {
+        #[derive(genja_core_derive::DelegateMacro)]
+        #[delegate(len, iter, contains_key)]
+        struct Ports(CustomTreeMap<u16>);
+
+        let mut inner = CustomTreeMap::new();
+        inner.insert("ssh", 22);
+        let ports = Ports(inner);
+        assert_eq!(ports.len(), 1);
+        assert!(ports.contains_key("ssh"));
+        assert!(!ports.contains_key("netconf"));
+        assert_eq!(
+            ports.iter().map(|(name, _)| name.as_str()).next(),
+            Some("ssh")
+        );
+    }
+
     #[test]
     fn test_custom_tree_map_ordering() {
         let mut tree = CustomTreeMap::new();
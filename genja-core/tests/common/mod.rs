@@ -1,6 +1,6 @@
 use genja_core::inventory::{
-    BaseBuilderHost, ConnectionManager, Data, Host, Hosts, Inventory, TransformFunction,
-    TransformFunctionOptions,
+    BaseBuilderHost, ConnectionManager, Data, GroupResolution, Host, Hosts, Inventory,
+    TransformFunction, TransformFunctionOptions, SCHEMA_VERSION,
 };
 use serde_json::json;
 use std::sync::Arc;
@@ -61,12 +61,15 @@ pub fn inventory_setup() -> Result<Inventory, Box<dyn std::error::Error>> {
     hosts.add_host(host2);
 
     let inventory = Inventory {
+        schema_version: SCHEMA_VERSION,
         hosts,
         groups: None,
         defaults: None,
         transform_function: Some(transform_function),
         transform_function_options: Some(transform_options),
+        group_resolution: GroupResolution::default(),
         connections: Arc::new(ConnectionManager::default()),
+        issues: Vec::new(),
     };
     Ok(inventory)
 }
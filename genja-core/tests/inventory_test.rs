@@ -1,10 +1,14 @@
+use genja_core::connections::DeviceErrorKind;
 use genja_core::inventory::{
-    BaseBuilderHost, ConnectionKey, ConnectionManager, ConnectionOptions, Data, Defaults, Host,
-    Hosts, Inventory, ParentGroups, TransformFunctionOptions,
+    BaseBuilderHost, ConnectionKey, ConnectionManager, ConnectionOptions, Data, Defaults,
+    GroupResolution, Host, Hosts, Inventory, ParentGroups, SharedInventory,
+    TransformFunctionOptions, SCHEMA_VERSION,
 };
+use genja_core::Genja;
 use serde_json::json;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Barrier};
+use std::thread;
 mod common;
 
 fn build_connection_options(
@@ -92,12 +96,15 @@ fn inventory_can_model_mock_network_devices() {
     hosts.add_host(switch);
 
     let inventory = Inventory {
+        schema_version: SCHEMA_VERSION,
         hosts,
         groups: None,
         defaults: Some(defaults.clone()),
         transform_function: None,
         transform_function_options: Some(transform_options.clone()),
+        group_resolution: GroupResolution::default(),
         connections: Arc::new(ConnectionManager::default()),
+        issues: Vec::new(),
     };
 
     assert_eq!(inventory.hosts.len(), 2);
@@ -216,3 +223,230 @@ fn connection_manager_creates_connections_lazily() {
     assert_eq!(created.load(Ordering::SeqCst), 1);
     assert!(Arc::ptr_eq(&first, &second));
 }
+
+#[test]
+fn connection_manager_get_or_create_is_race_free() {
+    #[derive(Debug)]
+    struct SlowConnection;
+
+    impl genja_core::inventory::Connection for SlowConnection {
+        fn is_alive(&self) -> bool {
+            true
+        }
+
+        fn open(
+            &mut self,
+            _params: &genja_core::inventory::ResolvedConnectionParams,
+        ) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn close(&mut self) -> ConnectionKey {
+            ConnectionKey::new("router1.lab", "ssh2")
+        }
+    }
+
+    const THREADS: usize = 16;
+
+    for _ in 0..50 {
+        let manager = ConnectionManager::default();
+        let created = AtomicUsize::new(0);
+        let barrier = Barrier::new(THREADS);
+
+        let connections: Vec<_> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..THREADS)
+                .map(|_| {
+                    scope.spawn(|| {
+                        barrier.wait();
+                        manager.get_or_create(ConnectionKey::new("router1.lab", "ssh2"), || {
+                            created.fetch_add(1, Ordering::SeqCst);
+                            thread::yield_now();
+                            SlowConnection
+                        })
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        assert_eq!(created.load(Ordering::SeqCst), 1);
+        assert_eq!(manager.len(), 1);
+        assert!(connections.iter().all(|c| Arc::ptr_eq(c, &connections[0])));
+    }
+}
+
+#[test]
+fn genja_preconnect_opens_filtered_hosts() {
+    #[derive(Debug, Default)]
+    struct LabConnection {
+        open: bool,
+    }
+
+    impl genja_core::inventory::Connection for LabConnection {
+        fn is_alive(&self) -> bool {
+            self.open
+        }
+
+        fn open(
+            &mut self,
+            params: &genja_core::inventory::ResolvedConnectionParams,
+        ) -> Result<(), String> {
+            if params.hostname == "192.0.2.99" {
+                return Err("connection timed out".to_string());
+            }
+            self.open = true;
+            Ok(())
+        }
+
+        fn close(&mut self) -> ConnectionKey {
+            self.open = false;
+            ConnectionKey::new("", "lab")
+        }
+    }
+
+    let mut hosts = Hosts::new();
+    for (name, hostname) in [
+        ("r1", "192.0.2.1"),
+        ("r2", "192.0.2.2"),
+        ("r10", "192.0.2.99"),
+        ("s1", "192.0.2.3"),
+    ] {
+        hosts.add_host(Host::builder(name).hostname(hostname).build());
+    }
+    let genja = Genja::new(Inventory::builder().hosts(hosts).build());
+    let routers = genja.filter(|host| host.name.starts_with('r'));
+
+    let report = routers.preconnect("lab", 2, LabConnection::default);
+    assert_eq!(report.connected, vec!["r1", "r2"]);
+    assert_eq!(
+        report.failed.get("r10").map(String::as_str),
+        Some("connection timed out")
+    );
+    assert_eq!(
+        report.failures_by_kind()[&DeviceErrorKind::Timeout],
+        vec!["r10"]
+    );
+
+    // Already open connections are reused, the failed one is retried.
+    let report = routers.preconnect("lab", 8, LabConnection::default);
+    assert_eq!(report.connected.len(), 2);
+    assert_eq!(report.failed.len(), 1);
+}
+
+#[test]
+fn genja_read_only_view_keeps_selection() {
+    let inventory = common::inventory_setup().expect("inventory setup should succeed");
+    let genja = Genja::new(inventory);
+    let routers = genja.filter(|host| host.name.starts_with("router"));
+
+    let view = routers.read_only();
+    assert_eq!(view.host_count(), 1);
+    assert_eq!(view.iter_all_hosts().count(), 2);
+    assert!(view.get_host("router1.lab").is_some());
+    assert!(view.get_host("switch1.lab").is_none());
+
+    let none = view.filter(|host| host.platform.is_some());
+    assert_eq!(none.host_count(), 0);
+}
+
+#[test]
+fn genja_views_refresh_after_inventory_reload() {
+    let inventory = |names: &[&str]| {
+        let mut hosts = Hosts::new();
+        for name in names {
+            hosts.add_host(Host::new(name));
+        }
+        Inventory::builder().hosts(hosts).build().freeze()
+    };
+    let source = Arc::new(SharedInventory::new(inventory(&["r1", "r2", "s1"])));
+    let mut genja = Genja::from_shared(Arc::clone(&source));
+    let mut routers = genja.filter(|host| host.name.starts_with('r'));
+    assert_eq!(routers.host_count(), 2);
+
+    assert_eq!(source.replace(inventory(&["r2", "r3", "s1"])), 1);
+    assert!(genja.is_stale() && routers.is_stale());
+    // Stale views keep reading the inventory they were created from.
+    assert_eq!(routers.iter_hosts().count(), 2);
+
+    assert!(genja.refresh());
+    assert!(!genja.refresh());
+    assert_eq!(genja.generation(), 1);
+    let names: Vec<&str> = genja.iter_hosts().map(|host| host.name.as_str()).collect();
+    assert_eq!(names, ["r2", "r3", "s1"]);
+
+    assert!(routers.refresh());
+    let names: Vec<&str> = routers
+        .iter_hosts()
+        .map(|host| host.name.as_str())
+        .collect();
+    assert_eq!(names, ["r2"]);
+
+    // New filters start from the current inventory, even on a stale view.
+    source.replace(inventory(&["r4"]));
+    assert_eq!(genja.filter(|_| true).host_count(), 1);
+}
+
+#[test]
+fn genja_preconnect_skips_closed_scheduling_windows() {
+    #[derive(Debug, Default)]
+    struct LabConnection(bool);
+
+    impl genja_core::inventory::Connection for LabConnection {
+        fn is_alive(&self) -> bool {
+            self.0
+        }
+
+        fn open(
+            &mut self,
+            _params: &genja_core::inventory::ResolvedConnectionParams,
+        ) -> Result<(), String> {
+            self.0 = true;
+            Ok(())
+        }
+
+        fn close(&mut self) -> ConnectionKey {
+            self.0 = false;
+            ConnectionKey::new("", "lab")
+        }
+    }
+
+    // A window starting in an hour is closed now.
+    let minute = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        / 60
+        % 1440;
+    let (start, end) = ((minute + 60) % 1440, (minute + 120) % 1440);
+    let window = format!(
+        "{:02}:{:02}-{:02}:{:02}",
+        start / 60,
+        start % 60,
+        end / 60,
+        end % 60
+    );
+    let inventory: Inventory = serde_json::from_value(json!({
+        "hosts": {
+            "r1": { "name": "r1", "groups": ["pop"] },
+            "r2": { "name": "r2" }
+        },
+        "groups": {
+            "pop": { "data": { "scheduling": { "window": window, "max_concurrent": 1 } } }
+        }
+    }))
+    .unwrap();
+    let genja = Genja::new(inventory);
+
+    let report = genja.preconnect("lab", 2, LabConnection::default);
+    assert_eq!(report.connected, vec!["r2"]);
+    assert!(report.failed.is_empty());
+    assert_eq!(
+        report.skipped.get("r1").map(String::as_str),
+        Some("outside the scheduling window of group `pop`")
+    );
+
+    let limiter = genja.group_limiter();
+    assert!(!limiter.is_empty());
+    let r1 = genja.inventory().hosts.get("r1").unwrap();
+    let _permit = limiter.acquire(r1);
+}